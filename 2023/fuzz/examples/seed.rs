@@ -0,0 +1,17 @@
+//! Seeds every target's corpus with the examples from its day's tests.
+
+use std::{fs, io::Result, path::Path};
+
+fn main() -> Result<()> {
+	let fuzz_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+	for target in fs::read_dir(fuzz_dir.join("fuzz_targets"))? {
+		let day = target?.path().file_stem().unwrap().to_owned();
+		let source = fs::read_to_string(fuzz_dir.join("../src").join(&day).with_extension("rs"))?;
+		let corpus = fuzz_dir.join("corpus").join(&day);
+		fs::create_dir_all(&corpus)?;
+		for (name, example) in aoc_common::examples::from_source(&source) {
+			fs::write(corpus.join(name), example)?;
+		}
+	}
+	Ok(())
+}
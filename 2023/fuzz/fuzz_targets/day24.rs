@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc2023::day24::{part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+	let _ = part1(input);
+	let _ = part2(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc2023::day25::part1;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+	let _ = part1(input);
+});
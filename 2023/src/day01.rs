@@ -0,0 +1,95 @@
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	to_lines,
+};
+
+fn map_digit(s: &str, dict: &[&str]) -> Option<i64> {
+	dict.iter()
+		.position(|d| s.starts_with(d))
+		.map(|pos| pos as i64 + 1)
+}
+
+fn to_digits(line: &str, include_words: bool) -> Vec<i64> {
+	const DIGITS: &[&str] = &["1", "2", "3", "4", "5", "6", "7", "8", "9"];
+	const DIGIT_WORDS: &[&str] = &[
+		"one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+	];
+
+	(0..line.len())
+		.filter_map(|idx| {
+			map_digit(&line[idx..], DIGITS).or(include_words
+				.then(|| map_digit(&line[idx..], DIGIT_WORDS))
+				.flatten())
+		})
+		.collect()
+}
+
+/// The first and last digit of every line, counting spelled out ones too if `include_words` is set.
+pub fn parse(input: &str, include_words: bool) -> Result<Vec<(i64, i64)>> {
+	to_lines(input)
+		.map(|line| {
+			let digits = to_digits(line, include_words);
+			let fst = digits
+				.first()
+				.ok_or_eyre("line should have at least one digit")?;
+			let lst = digits
+				.last()
+				.ok_or_eyre("line should have at least one digit")?;
+			Ok((*fst, *lst))
+		})
+		.collect()
+}
+
+fn common(input: &str, include_words: bool) -> Result<i64> {
+	let res = parse(input, include_words)?
+		.into_iter()
+		.map(|(fst, lst)| fst * 10 + lst)
+		.sum();
+	Ok(res)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	common(input, false)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	common(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT_1: &str = r#"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"#;
+
+	static EX_INPUT_2: &str = r#"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT_1).unwrap(), 142);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT_2).unwrap(), 281);
+	}
+
+	#[test]
+	fn invalid() {
+		assert!(part1("abc\n").is_err());
+		assert!(part2("1abc\nxyz\n").is_err());
+	}
+}
@@ -0,0 +1,107 @@
+use std::{collections::HashSet, ops::Range};
+
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::Grid,
+	rangemap::RangeMap,
+	regex::Regex,
+};
+
+/// A number, along with where it is in the input.
+pub type PartNumber = (Range<usize>, i64);
+
+/// The schematic, and every number in it.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Vec<PartNumber>)> {
+	let grid = Grid::for_str(input).ok_or_eyre("invalid grid")?;
+
+	let re = Regex::new(r"\d+").unwrap();
+	let nums = re
+		.find_iter(input)
+		.map(|m| Ok((m.range(), m.as_str().parse()?)))
+		.collect::<Result<_>>()?;
+
+	Ok((grid, nums))
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let (grid, nums) = parse(input)?;
+
+	let check_symbol = |b: u8| b != b'.' && !b.is_ascii_digit();
+
+	let res = nums
+		.into_iter()
+		.filter(|(Range { start, end }, _)| {
+			let start_pos = grid.idx_to_pos(*start).unwrap();
+			let end_pos = grid.idx_to_pos(end - 1).unwrap();
+
+			grid.adjacent_area(start_pos, end_pos)
+				.any(|pos| check_symbol(grid[pos]))
+		})
+		.map(|(_, num)| num)
+		.sum();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let (grid, nums) = parse(input)?;
+
+	let mut num_map: RangeMap<_, i64> = nums.into_iter().collect();
+
+	let gears = input
+		.as_bytes()
+		.iter()
+		.enumerate()
+		.filter(|(_, &b)| b == b'*');
+
+	let mut res = 0;
+	for (gear, _) in gears {
+		let pos_to_check = grid.adjacent_pos(grid.idx_to_pos(gear).unwrap());
+
+		let part_nums: HashSet<_> = pos_to_check
+			.map(|pos| grid.pos_to_idx(pos).unwrap())
+			.filter_map(|idx| num_map.get_key_value(&idx))
+			.map(|(r, &v)| (r.clone(), v))
+			.collect();
+
+		if part_nums.len() != 2 {
+			continue;
+		}
+
+		let mut ratio = 1;
+		for (r, v) in part_nums {
+			num_map.remove(r);
+			ratio *= v;
+		}
+
+		res += ratio;
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 4361);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 467835);
+	}
+}
@@ -0,0 +1,78 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::parse::{labeled, numbers};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	to_lines,
+};
+
+fn parse_game(line: &str) -> Result<usize> {
+	let (_, nums) = labeled(line)?;
+	let (win, ours) = nums.split_once('|').ok_or_eyre("invalid format")?;
+
+	let win: HashSet<i64> = numbers(win)?.into_iter().collect();
+	let ours: HashSet<i64> = numbers(ours)?.into_iter().collect();
+
+	Ok(win.intersection(&ours).count())
+}
+
+/// How many winning numbers every card has.
+pub fn parse(input: &str) -> Result<Vec<usize>> {
+	to_lines(input).map(parse_game).collect()
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let res = parse(input)?
+		.into_iter()
+		.map(|wins| match wins {
+			0 => 0,
+			exp => i64::pow(2, exp as u32 - 1),
+		})
+		.sum();
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let wins = parse(input)?;
+
+	let res = wins
+		.into_iter()
+		.scan(VecDeque::new(), |pile, wins| {
+			let copies = pile.pop_front().unwrap_or(0) + 1;
+			for i in 0..wins {
+				if let Some(e) = pile.get_mut(i) {
+					*e += copies;
+				} else {
+					pile.push_back(copies);
+				}
+			}
+			Some(copies)
+		})
+		.sum();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT_1: &str = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT_1).unwrap(), 13);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT_1).unwrap(), 30);
+	}
+}
@@ -0,0 +1,166 @@
+use std::mem::replace;
+
+use aoc_common::parse::{labeled_numbers, sections, value, words};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	iter::IterExt,
+	to_lines,
+};
+
+/// `[dst, src, len]` ranges mapping one category to the next.
+pub type Mapping = Vec<[i64; 3]>;
+
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<Mapping>)> {
+	let mut sections = sections(input);
+	let (_, seeds) = labeled_numbers(sections.next().ok_or_eyre("no seeds")?)?;
+	let categories = sections
+		.map(|cat| {
+			to_lines(cat)
+				.skip(1)
+				.map(|line| {
+					let [dst, src, len] = words(line)?;
+					Ok([value(dst)?, value(src)?, value(len)?])
+				})
+				.collect()
+		})
+		.collect::<Result<_>>()?;
+	Ok((seeds, categories))
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let (seeds, categories) = parse(input)?;
+
+	let res = categories
+		.into_iter()
+		.fold(seeds, |mut seeds, cat| {
+			let mut res = Vec::new();
+
+			for [dst, src, len] in cat {
+				let max_src = src + len;
+				let d = src - dst;
+
+				for i in (0..seeds.len()).rev() {
+					if seeds[i] >= src && seeds[i] < max_src {
+						res.push(seeds.remove(i) - d);
+					}
+				}
+			}
+
+			res.append(&mut seeds);
+
+			res
+		})
+		.into_iter()
+		.min()
+		.unwrap();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let (seeds, categories) = parse(input)?;
+	let seeds: Vec<(i64, i64)> = seeds
+		.into_iter()
+		.arr_chunks()
+		.map(|[start, len]| (start, len))
+		.collect();
+
+	let res = categories
+		.into_iter()
+		.fold(seeds, |mut seeds, cat| {
+			let mut res = Vec::new();
+
+			for [dst, src, len] in cat {
+				let max_src = src + len;
+				let d = src - dst;
+
+				for i in (0..seeds.len()).rev() {
+					let (cs, clen) = seeds[i];
+					let last = cs + clen - 1;
+					if cs >= max_src {
+						continue;
+					} else if cs >= src {
+						let offset = cs - src;
+						let nlen = clen.min(len - offset);
+						res.push((cs - d, nlen));
+
+						if nlen < clen {
+							let diff = clen - nlen;
+							let _ = replace(&mut seeds[i], (cs + nlen, diff));
+						} else {
+							seeds.remove(i);
+						}
+					} else if last >= src {
+						let offset = src - cs;
+						let nlen = len.min(clen - offset);
+						res.push((cs + offset - d, nlen));
+						let _ = replace(&mut seeds[i], (cs, offset));
+						if last >= max_src {
+							seeds.insert(i + 1, (cs + offset + nlen, clen - nlen - offset));
+						}
+					}
+				}
+			}
+
+			res.append(&mut seeds);
+
+			res
+		})
+		.into_iter()
+		.map(|(s, _)| s)
+		.min()
+		.unwrap();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 35);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 46);
+	}
+}
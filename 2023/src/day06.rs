@@ -0,0 +1,55 @@
+use aoc_common::parse::{labeled, numbers, value};
+use aoc_lib::color_eyre::eyre::{OptionExt, Result};
+
+fn find_res(time: i64, dist: i64) -> i64 {
+	(1..time)
+		.filter(|t| {
+			let rest = time - t;
+			t * rest > dist
+		})
+		.count() as i64
+}
+
+/// The numbers after the `Time:` and `Distance:` labels, each line read with `nums`.
+pub fn parse<T>(input: &str, nums: impl Fn(&str) -> Result<T>) -> Result<(T, T)> {
+	let (time, distance) = input.trim().split_once('\n').ok_or_eyre("invalid format")?;
+	Ok((nums(labeled(time)?.1)?, nums(labeled(distance)?.1)?))
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let (time, distance) = parse(input, numbers::<i64>)?;
+
+	let res = time
+		.into_iter()
+		.zip(distance)
+		.map(|(time, distance)| find_res(time, distance))
+		.product();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let (time, distance) = parse(input, |nums| value(&nums.replace(' ', "")))?;
+
+	Ok(find_res(time, distance))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+Time:      7  15   30
+Distance:  9  40  200
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 288);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 71503);
+	}
+}
@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+	checked::mul,
+	intern::{Id, Interner},
+};
+use aoc_lib::{
+	color_eyre::eyre::{bail, OptionExt, Result},
+	to_lines,
+};
+
+/// The instructions, and the nodes with their left and right neighbors.
+pub struct Network<'a> {
+	pub ins: &'a [u8],
+	pub nodes: Interner<'a>,
+	/// The left and right neighbors of every node, indexed by id.
+	pub next: Vec<[Id; 2]>,
+}
+
+impl Network<'_> {
+	fn step(&self, node: Id, dir: u8) -> Id {
+		let [left, right] = self.next[node as usize];
+		match dir {
+			b'L' => left,
+			b'R' => right,
+			_ => unreachable!(),
+		}
+	}
+}
+
+pub fn parse(input: &str) -> Network<'_> {
+	let mut input = to_lines(input);
+
+	let ins = input.next().unwrap().as_bytes();
+
+	let mut nodes = Interner::new();
+	let mut next = Vec::new();
+	for line in input.skip(1) {
+		let (from, to) = line.split_once(" = ").unwrap();
+		let (left, right) = to[1..to.len() - 1].split_once(", ").unwrap();
+
+		let [from, left, right] = [from, left, right].map(|node| nodes.intern(node));
+		// nodes which are only ever pointed to don't lead anywhere
+		next.resize(nodes.len(), [Id::MAX; 2]);
+		next[from as usize] = [left, right];
+	}
+
+	Network { ins, nodes, next }
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let network = parse(input);
+	let start = network
+		.nodes
+		.get("AAA")
+		.ok_or_eyre("there is no AAA node")?;
+	let end = network
+		.nodes
+		.get("ZZZ")
+		.ok_or_eyre("there is no ZZZ node")?;
+
+	let res = network
+		.ins
+		.iter()
+		.cycle()
+		.scan(start, |state, &dir| {
+			*state = network.step(*state, dir);
+			Some(*state)
+		})
+		.position(|curr| curr == end)
+		.unwrap() as i64;
+
+	Ok(res + 1)
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+	if b == 0 {
+		a
+	} else {
+		gcd(b, a % b)
+	}
+}
+
+fn lcm(a: u128, b: u128) -> u128 {
+	mul(a / gcd(a, b), b)
+}
+
+/// Steps it takes the ghost starting at `start` to reach an exit,
+/// making sure it keeps reaching exits only every that many steps, forever.
+fn exit_period(start: Id, network: &Network, exits: &[bool]) -> Result<u128> {
+	let ins = network.ins;
+	let name = |node| network.nodes.name(node);
+	// Going this long without an exit means the ghost is going in circles without one.
+	let max_gap = network.nodes.len() * ins.len();
+	// Exits the ghost has been on, and where in the instructions it was at the time.
+	// Once one of them repeats, the ghost will only ever reach the exits it already has.
+	let mut seen = HashSet::new();
+	let mut period = None;
+	let mut last_exit = 0;
+	let mut node = start;
+
+	for (step, &dir) in (1..).zip(ins.iter().cycle()) {
+		node = network.step(node, dir);
+
+		if step - last_exit > max_gap {
+			match period {
+				None => bail!("ghost starting at {} never reaches a node ending in Z", name(start)),
+				Some(_) => bail!("ghost starting at {} doesn't cycle: it stops reaching exits after {last_exit} steps", name(start)),
+			}
+		}
+		if !exits[node as usize] {
+			continue;
+		}
+
+		let period = *period.get_or_insert(step);
+		// checking the steps are multiples of the period isn't enough, it could skip some of them
+		if step - last_exit != period {
+			bail!("ghost starting at {} doesn't have a constant period: it reaches {} after {step} steps, {} after the previous exit instead of {period}", name(start), name(node), step - last_exit);
+		}
+		if !seen.insert((node, step % ins.len())) {
+			return Ok(period as u128);
+		}
+		last_exit = step;
+	}
+	unreachable!()
+}
+
+// This only works because every path properly cycles,
+// and every period is constant.
+// I tried to be general at first because I naturally assumed this wouldn't be the case,
+// but we can't have nice things.
+// I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC
+// I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC
+// I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC
+// I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC
+// I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC I HATE AOC
+pub fn part2(input: &str) -> Result<u128> {
+	let network = parse(input);
+	let exits: Vec<_> = network
+		.nodes
+		.iter()
+		.map(|(_, node)| node.ends_with('Z'))
+		.collect();
+
+	let periods = network
+		.nodes
+		.iter()
+		.filter(|(_, node)| node.ends_with('A'))
+		.map(|(start, _)| exit_period(start, &network, &exits))
+		.collect::<Result<Vec<_>>>()?;
+	let res = periods.into_iter().reduce(lcm).unwrap();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT_1: &str = r#"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+"#;
+
+	static EX_INPUT_2: &str = r#"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT_1).unwrap(), 2);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT_2).unwrap(), 6);
+	}
+
+	#[test]
+	fn part2_inconstant_period() {
+		let input = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11Z, 11Z)\n";
+		let err = part2(input).unwrap_err().to_string();
+		assert!(err.contains("doesn't have a constant period"), "{err}");
+
+		// exits after 1, 3, 5... steps, all multiples of the first
+		let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (22Z, 22Z)\n22Z = (11B, 11B)\n";
+		let err = part2(input).unwrap_err().to_string();
+		assert!(err.contains("doesn't have a constant period"), "{err}");
+	}
+}
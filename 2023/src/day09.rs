@@ -0,0 +1,70 @@
+use aoc_lib::{color_eyre::eyre::Result, iter::IterExt, to_lines};
+
+fn extrapolate(d: impl Iterator<Item = i64>) -> i64 {
+	let mut p = d.peekable2();
+
+	let drv: Vec<_> = std::iter::from_fn(|| match p.peek_pair() {
+		(Some(&a), Some(&b)) => {
+			p.next();
+			Some(b - a)
+		}
+		_ => None,
+	})
+	.collect();
+
+	if drv.iter().all(|e| *e == 0) {
+		p.next().unwrap()
+	} else {
+		let last = p.next().unwrap();
+		last + extrapolate(drv.into_iter())
+	}
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+	to_lines(input)
+		.map(|line| {
+			line.split_ascii_whitespace()
+				.map(|n| Ok(n.parse()?))
+				.collect()
+		})
+		.collect()
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let res = parse(input)?
+		.into_iter()
+		.map(|nums| extrapolate(nums.into_iter()))
+		.sum();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let res = parse(input)?
+		.into_iter()
+		.map(|nums| extrapolate(nums.into_iter().rev()))
+		.sum();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 114);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 2);
+	}
+}
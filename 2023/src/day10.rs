@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::{
+	color_eyre::eyre::Result,
+	grid::{Grid, Point, PointExt},
+	to_lines,
+};
+
+fn max_dist(pipes: &HashMap<Point, Vec<Point>>, start: Point) -> i64 {
+	let (mut prev_left, mut prev_right) = (start, start);
+	let (mut left, mut right) = {
+		let adj = pipes.get(&start).unwrap();
+		(adj[0], adj[1])
+	};
+	let mut d1 = 1;
+	let mut d2 = 1;
+
+	while left != right {
+		let new_left = pipes
+			.get(&left)
+			.unwrap()
+			.iter()
+			.copied()
+			.find(|&pipe| pipe != prev_left)
+			.unwrap();
+		let new_right = pipes
+			.get(&right)
+			.unwrap()
+			.iter()
+			.copied()
+			.find(|&pipe| pipe != prev_right)
+			.unwrap();
+		d1 += 1;
+		d2 += 1;
+		(prev_left, left, prev_right, right) = (left, new_left, right, new_right);
+	}
+
+	d1.max(d2)
+}
+
+fn find_main_loop(pipes: &HashMap<Point, Vec<Point>>, start: Point) -> HashSet<Point> {
+	let mut main_loop = HashSet::from([start]);
+	let mut stack = vec![start];
+
+	while let Some(curr) = stack.pop() {
+		for &pos in pipes.get(&curr).unwrap() {
+			if main_loop.insert(pos) {
+				stack.push(pos);
+			}
+		}
+	}
+
+	main_loop
+}
+
+/// The size of the grid, where every pipe leads and where the animal starts.
+// this is all useless but it was my first instinct to parse it this way, oh well
+pub fn parse(input: &str) -> (Point, HashMap<Point, Vec<Point>>, Point) {
+	let grid = Grid::for_str(input).unwrap();
+
+	let mut pipes: HashMap<_, Vec<_>> = to_lines(input)
+		.enumerate()
+		.flat_map(|(y, line)| {
+			let y = y as i64;
+			line.as_bytes()
+				.iter()
+				.enumerate()
+				.filter_map(move |(x, &c)| {
+					let pos = (x as i64, y);
+					let adj = |ds: [Point; 2]| {
+						ds.into_iter()
+							.map(|d| pos.add(&d))
+							.filter(|&pos| grid.is_valid_pos(pos))
+							.collect()
+					};
+					let v = match c {
+						b'.' => return None,
+						b'|' => adj([(0, -1), (0, 1)]),
+						b'-' => adj([(-1, 0), (1, 0)]),
+						b'L' => adj([(0, -1), (1, 0)]),
+						b'J' => adj([(0, -1), (-1, 0)]),
+						b'7' => adj([(-1, 0), (0, 1)]),
+						b'F' => adj([(1, 0), (0, 1)]),
+						b'S' => Vec::new(),
+						c => panic!("Unexpected character in input: {}", c as char),
+					};
+					Some((pos, v))
+				})
+		})
+		.collect();
+
+	// find and fix up starting position
+	let start_idx = input.trim().find('S').unwrap();
+	let start_pos = grid.idx_to_pos(start_idx).unwrap();
+	let start_adj = grid
+		.adjacent_pos(start_pos)
+		.filter(|pos| {
+			pipes
+				.get(pos)
+				.map(|adj| adj.iter().any(|&pos| pos == start_pos))
+				.unwrap_or(false)
+		})
+		.collect();
+	*pipes.get_mut(&start_pos).unwrap() = start_adj;
+
+	((grid.width(), grid.height()), pipes, start_pos)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let (_, pipes, start_pos) = parse(input);
+
+	Ok(max_dist(&pipes, start_pos))
+}
+
+/// Check whether a pipe is a "cross" pipe.
+/// A "cross" pipe is any pipe that has a connection above it,
+/// meaning when we cross it, we're either entering the pipe loop or leaving it.
+/// The *above* is arbitrary, it could be below as well.
+/// It could even be left/right, if I went for vertical scanlines instead.
+///
+/// The point is to not count situations like: └┐ as entering and leaving the loop,
+/// hence we count └, but not ┐.
+fn is_cross((x, y): Point, pipes: &HashMap<Point, Vec<Point>>) -> bool {
+	pipes
+		.get(&(x, y))
+		.map(|adj| adj.iter().any(|&(_, other_y)| other_y == y - 1))
+		.unwrap_or(false)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let ((width, height), pipes, start_pos) = parse(input);
+
+	let main_loop = find_main_loop(&pipes, start_pos);
+
+	let mut res = 0;
+	for y in 0..height {
+		let mut inside = false;
+		for x in 0..width {
+			if main_loop.contains(&(x, y)) {
+				if is_cross((x, y), &pipes) {
+					inside = !inside;
+				}
+			} else if inside {
+				res += 1;
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use aoc_common::{pipes::pipe_maze, rng::Rng};
+
+	use super::*;
+
+	static EX_INPUT_1: &str = r#"
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+"#;
+
+	static EX_INPUT_2: &str = r#"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+"#;
+
+	const EX_INPUT_2_SOL: i64 = 10;
+
+	static EX_INPUT_3: &str = r#"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+"#;
+
+	const EX_INPUT_3_SOL: i64 = 4;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT_1).unwrap(), 8);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT_2).unwrap(), EX_INPUT_2_SOL);
+	}
+
+	#[test]
+	fn part2_example_3() {
+		assert_eq!(part2(EX_INPUT_3).unwrap(), EX_INPUT_3_SOL);
+	}
+
+	/// A loop snaking through every tile of a `width` by `height` grid, starting in the top left.
+	/// `height` has to be even for the snake to make it back to the start.
+	fn snake(width: i64, height: i64) -> String {
+		let mut path: Vec<Point> = (0..width).map(|x| (x, 0)).collect();
+		for y in 1..height {
+			let row = (1..width).map(|x| (x, y));
+			if y % 2 == 1 {
+				path.extend(row.rev());
+			} else {
+				path.extend(row);
+			}
+		}
+		path.extend((1..height).rev().map(|y| (0, y)));
+
+		let mut grid = vec![vec![b'.'; width as usize]; height as usize];
+		for (i, &(x, y)) in path.iter().enumerate() {
+			let prev = path[(i + path.len() - 1) % path.len()];
+			let next = path[(i + 1) % path.len()];
+			let has = |d: Point| prev == (x + d.0, y + d.1) || next == (x + d.0, y + d.1);
+			grid[y as usize][x as usize] = match (has((0, -1)), has((0, 1)), has((-1, 0))) {
+				_ if i == 0 => b'S',
+				(true, true, _) => b'|',
+				(true, _, true) => b'J',
+				(true, _, false) => b'L',
+				(_, true, true) => b'7',
+				(_, true, false) => b'F',
+				(false, false, _) => b'-',
+			};
+		}
+
+		grid.into_iter()
+			.map(|row| String::from_utf8(row).unwrap() + "\n")
+			.collect()
+	}
+
+	#[test]
+	fn long_loop() {
+		let input = snake(500, 500);
+		assert_eq!(part1(&input).unwrap(), 500 * 500 / 2);
+		assert_eq!(part2(&input).unwrap(), 0);
+	}
+
+	#[test]
+	fn random_loops() {
+		for seed in 0..2000 {
+			let mut rng = Rng::new(seed);
+			let (width, height) = (rng.range(2..25), rng.range(2..25));
+			let maze = pipe_maze(width, height, &mut rng);
+			assert_eq!(part1(&maze.input).unwrap(), maze.farthest, "seed {seed}");
+			assert_eq!(part2(&maze.input).unwrap(), maze.enclosed, "seed {seed}");
+		}
+	}
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use aoc_common::grid_buf::GridBuf;
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::Point,
+};
+
+fn galaxies(grid: &GridBuf<u8>) -> Vec<Point> {
+	grid.iter()
+		.filter(|&(_, &c)| c == b'#')
+		.map(|(pos, _)| pos)
+		.collect()
+}
+
+pub fn parse(input: &str) -> Result<GridBuf<u8>> {
+	GridBuf::for_str(input).ok_or_eyre("invalid format")
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+	let mut grid = parse(input)?;
+
+	// expand rows
+	for y in (0..grid.height()).rev() {
+		if grid.row(y).iter().all(|&c| c == b'.') {
+			grid.insert_row(y, grid.row(y).to_vec());
+		}
+	}
+
+	// expand columns
+	for x in (0..grid.width()).rev() {
+		if grid.column(x).all(|&c| c == b'.') {
+			grid.insert_column(x, vec![b'.'; grid.height() as usize]);
+		}
+	}
+
+	let galaxies = galaxies(&grid);
+
+	let mut res = 0;
+	for i in 0..galaxies.len() - 1 {
+		let (ax, ay) = galaxies[i];
+		for &(bx, by) in &galaxies[i..] {
+			let d = ax.abs_diff(bx) + ay.abs_diff(by);
+			res += d;
+		}
+	}
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	#[cfg(not(test))]
+	const EMPTY_ROW_SCALE: i64 = 1_000_000;
+	#[cfg(test)]
+	const EMPTY_ROW_SCALE: i64 = 10;
+
+	let grid = parse(input)?;
+
+	let mut expanded_rows = HashSet::new();
+	let mut expanded_cols = HashSet::new();
+
+	// expand rows
+	for y in (0..grid.height()).rev() {
+		if grid.row(y).iter().all(|&c| c == b'.') {
+			expanded_rows.insert(y);
+		}
+	}
+
+	// expand columns
+	for x in (0..grid.width()).rev() {
+		if grid.column(x).all(|&c| c == b'.') {
+			expanded_cols.insert(x);
+		}
+	}
+
+	let galaxies = galaxies(&grid);
+
+	let mut res = 0;
+	for i in 0..galaxies.len() - 1 {
+		let (ax, ay) = galaxies[i];
+		for &(bx, by) in &galaxies[i..] {
+			let mut x = ax;
+			let xd = if ax < bx { 1 } else { -1 };
+			while x != bx {
+				if expanded_cols.contains(&x) {
+					res += EMPTY_ROW_SCALE;
+				} else {
+					res += 1;
+				}
+				x += xd;
+			}
+
+			let mut y = ay;
+			let yd = if ay < by { 1 } else { -1 };
+			while y != by {
+				if expanded_rows.contains(&y) {
+					res += EMPTY_ROW_SCALE;
+				} else {
+					res += 1;
+				}
+				y += yd;
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 374);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 1030);
+	}
+}
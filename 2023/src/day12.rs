@@ -0,0 +1,165 @@
+use aoc_common::memo::DenseMemo;
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	to_lines,
+};
+
+// God bless @deliveroo
+// Wouldn't have solved this day without him
+// VERY heavily based on https://github.com/MarekSosnicki-deliveroo/AOC_2023/blob/master/examples/day_12_p2.rs
+/// How many ways the `?`s in `s` can be filled in so its damaged springs form `groups`.
+pub fn test_str(
+	s: &str,
+	groups: &[usize],
+	memo: &mut DenseMemo<(usize, usize, usize), usize>,
+) -> usize {
+	fn recur(
+		s: &[u8],
+		groups: &[usize],
+		idx: usize,
+		group_idx: usize,
+		group_sz: usize,
+		memo: &mut DenseMemo<(usize, usize, usize), usize>,
+	) -> usize {
+		// A group that's already too long can't be completed,
+		// which also keeps `group_sz` within the memo's bounds.
+		if group_sz > groups.get(group_idx).copied().unwrap_or(0) {
+			return 0;
+		}
+		memo.get_or_insert_with((idx, group_idx, group_sz), |memo, _| {
+			let (mut group_idx, mut group_sz) = (group_idx, group_sz);
+			for idx in idx..s.len() {
+				match s[idx] {
+					b'.' => {
+						if group_sz > 0 {
+							if groups.get(group_idx) != Some(&group_sz) {
+								return 0;
+							}
+							group_idx += 1;
+						}
+						group_sz = 0;
+					}
+					b'#' => {
+						group_sz += 1;
+						if let Some(&expected_sz) = groups.get(group_idx) {
+							if group_sz > expected_sz {
+								return 0;
+							}
+						} else {
+							return 0;
+						}
+					}
+					b'?' => {
+						// If current group size is 0 (i.e. we haven't started a group yet),
+						// continue with the current group index.
+						let new_group_idx = (group_sz == 0).then_some(group_idx).or_else(|| {
+							// Otherwise...
+							groups
+								.get(group_idx)
+								// ...if the current group has reached its desired size yet...
+								.filter(|&&sz| sz == group_sz)
+								// ...continue to the next group.
+								.map(|_| group_idx + 1)
+							// If it hasn't, we don't need to try replacing the current ? with .
+						});
+						let a = new_group_idx
+							.map(|group_idx| recur(s, groups, idx + 1, group_idx, 0, memo))
+							.unwrap_or(0);
+						let b = recur(s, groups, idx + 1, group_idx, group_sz + 1, memo);
+						return a + b;
+					}
+					_ => unreachable!(),
+				}
+			}
+
+			if
+			// we are in a group
+			group_sz > 0
+			// the current group is the last one
+			&& group_idx == groups.len() - 1
+			// it is of the expected size
+			&& groups[group_idx] == group_sz
+			// or we aren't in a group and we have the corrent amount of groups
+			|| group_sz == 0 && group_idx == groups.len()
+			{
+				// That means we're in a corrent permutation
+				1
+			} else {
+				0
+			}
+		})
+	}
+
+	let max_group = groups.iter().copied().max().unwrap_or(0);
+	memo.reset((s.len() + 1, groups.len() + 1, max_group + 1));
+	recur(s.as_bytes(), groups, 0, 0, 0, memo)
+}
+
+/// Every row of springs along with the sizes of its groups of damaged ones.
+pub fn parse(input: &str) -> Result<Vec<(&str, Vec<usize>)>> {
+	to_lines(input)
+		.map(|line| {
+			let (chars, nums) = line.split_once(' ').ok_or_eyre("invalid format")?;
+			let nums = nums
+				.split(',')
+				.map(|n| Ok(n.parse()?))
+				.collect::<Result<_>>()?;
+			Ok((chars, nums))
+		})
+		.collect()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+	let s = std::time::Instant::now();
+	let mut memo = DenseMemo::dense((0, 0, 0));
+	let res = parse(input)?
+		.into_iter()
+		.map(|(chars, nums)| test_str(chars, &nums, &mut memo))
+		.sum();
+	println!("part 1 took {}ms", s.elapsed().as_millis());
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+	let s = std::time::Instant::now();
+	let mut memo = DenseMemo::dense((0, 0, 0));
+	let res = parse(input)?
+		.into_iter()
+		.map(|(c, mut nums)| {
+			let n = nums.clone();
+			let mut chars = c.to_string();
+			for _ in 0..4 {
+				nums.extend_from_slice(&n);
+				chars.push('?');
+				chars.push_str(c);
+			}
+			test_str(&chars, &nums, &mut memo)
+		})
+		.sum();
+	println!("part 2 took {}ms", s.elapsed().as_millis());
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 21);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 525152);
+	}
+}
@@ -0,0 +1,96 @@
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::Grid,
+	iter::IterExt,
+};
+
+/// Every pattern of ash and rocks.
+pub fn parse(input: &str) -> Result<Vec<Grid<'_>>> {
+	input
+		.trim()
+		.split("\n\n")
+		.map(|group| Grid::for_str(group).ok_or_eyre("invalid pattern"))
+		.collect()
+}
+
+fn solve(patterns: &[Grid], allowed_smudges: usize) -> i64 {
+	patterns
+		.iter()
+		.map(|group| {
+			let reflection_x = (1..group.width())
+				.find(|&x| {
+					let x = x as usize;
+					group
+						.iter_rows()
+						.flat_map(|row| {
+							let left = row.iter().take(x);
+							let right = row.iter().skip(x);
+
+							left.rev().zip(right).filter(|(a, b)| a != b)
+						})
+						.has_n(allowed_smudges)
+				})
+				.unwrap_or(0);
+
+			let reflection_y = (1..group.height())
+				.find(|&y| {
+					let y = y as usize;
+					group
+						.iter_columns()
+						.flat_map(|column| {
+							let above = column.iter().take(y);
+							let below = column.iter().skip(y);
+
+							above.rev().zip(below).filter(|(a, b)| a != b)
+						})
+						.has_n(allowed_smudges)
+				})
+				.unwrap_or(0);
+
+			reflection_x + 100 * (reflection_y)
+		})
+		.sum()
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let res = solve(&parse(input)?, 0);
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let res = solve(&parse(input)?, 1);
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 405);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 400);
+	}
+}
@@ -0,0 +1,208 @@
+use std::{collections::HashMap, ops::Range};
+
+use aoc_common::{
+	debugger::{self, step_requested, Simulation},
+	dir::Dir,
+	grid_buf::GridBuf,
+};
+use aoc_lib::color_eyre::eyre::{OptionExt, Result};
+
+fn load(grid: &GridBuf<u8>) -> i64 {
+	grid.iter()
+		.filter(|&(_, &c)| c == b'O')
+		.map(|((_, y), _)| grid.height() - y)
+		.sum()
+}
+
+/// Ranges of consecutive cells without a cube-shaped rock.
+fn free_ranges(line: impl Iterator<Item = u8>) -> Vec<Range<i64>> {
+	let mut res = Vec::<Range<i64>>::new();
+	for (i, c) in (0..).zip(line) {
+		if c == b'#' {
+			continue;
+		}
+		if let Some(last) = res.last_mut().filter(|last| last.end == i) {
+			last.end += 1;
+		} else {
+			res.push(i..i + 1);
+		}
+	}
+	res
+}
+
+pub fn parse(input: &str) -> Result<GridBuf<u8>> {
+	GridBuf::for_str(input).ok_or_eyre("invalid format")
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let mut grid = parse(input)?;
+
+	for x in 0..grid.width() {
+		let mut last_free_y = None;
+		for y in 0..grid.height() {
+			match grid[(x, y)] {
+				b'.' => {
+					if last_free_y.is_none() {
+						last_free_y = Some(y)
+					}
+				}
+				b'#' => last_free_y = None,
+				b'O' => {
+					if let Some(new_y) = last_free_y {
+						grid[(x, new_y)] = b'O';
+						grid[(x, y)] = b'.';
+						last_free_y = Some(new_y + 1);
+					}
+				}
+				_ => unreachable!(),
+			};
+		}
+	}
+
+	Ok(load(&grid))
+}
+
+/// The platform being tilted north, west, south and east, one tilt per step.
+#[derive(Debug, Clone)]
+pub struct Platform {
+	grid: GridBuf<u8>,
+	/// Free ranges of every column and every row, the stretches rocks roll along.
+	ranges_columns: Vec<Vec<Range<i64>>>,
+	ranges_rows: Vec<Vec<Range<i64>>>,
+	tilts: usize,
+}
+
+impl Platform {
+	const TILTS: [Dir; 4] = [Dir::Up, Dir::Left, Dir::Down, Dir::Right];
+
+	pub fn new(grid: GridBuf<u8>) -> Self {
+		let ranges_columns = grid
+			.iter_columns()
+			.map(|column| free_ranges(column.copied()))
+			.collect();
+		let ranges_rows = grid
+			.iter_rows()
+			.map(|row| free_ranges(row.iter().copied()))
+			.collect();
+		Platform {
+			grid,
+			ranges_columns,
+			ranges_rows,
+			tilts: 0,
+		}
+	}
+
+	fn tilt(&mut self, dir: Dir) {
+		let grid = &mut self.grid;
+		let lines = if dir.is_vertical() {
+			&self.ranges_columns
+		} else {
+			&self.ranges_rows
+		};
+		for (line, ranges) in (0..).zip(lines) {
+			let at = |i| {
+				if dir.is_vertical() {
+					(line, i)
+				} else {
+					(i, line)
+				}
+			};
+			for range in ranges {
+				let rocks = range.clone().filter(|&i| grid[at(i)] == b'O').count() as i64;
+				let rolled = match dir {
+					Dir::Up | Dir::Left => range.start..range.start + rocks,
+					Dir::Down | Dir::Right => range.end - rocks..range.end,
+				};
+				for i in range.clone() {
+					grid[at(i)] = if rolled.contains(&i) { b'O' } else { b'.' };
+				}
+			}
+		}
+	}
+
+	/// Runs a whole spin cycle, tilting the platform all four ways.
+	fn cycle(&mut self) {
+		for _ in Self::TILTS {
+			self.step();
+		}
+	}
+}
+
+impl Simulation for Platform {
+	fn step(&mut self) -> bool {
+		self.tilt(Self::TILTS[self.tilts % 4]);
+		self.tilts += 1;
+		true
+	}
+
+	fn render(&self) -> String {
+		self.grid.render(|&c| c as char)
+	}
+
+	fn counters(&self) -> Vec<(&'static str, String)> {
+		let next = Self::TILTS[self.tilts % 4].arrow() as char;
+		vec![
+			("cycle", (self.tilts / 4).to_string()),
+			("load", load(&self.grid).to_string()),
+			("next tilt", next.to_string()),
+		]
+	}
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	const CYCLE_COUNT: usize = 1_000_000_000;
+	let mut platform = Platform::new(parse(input)?);
+	if step_requested() {
+		debugger::run(platform.clone())?;
+	}
+
+	let mut cache = HashMap::<GridBuf<u8>, usize>::new();
+
+	let mut i = 0;
+	loop {
+		if i >= CYCLE_COUNT {
+			break;
+		}
+		if let Some(last_seen) = cache.get(&platform.grid) {
+			let d = i - last_seen;
+			if i + d < CYCLE_COUNT - 1 {
+				i += d;
+				continue;
+			}
+		}
+
+		cache.insert(platform.grid.clone(), i);
+		platform.cycle();
+		i += 1;
+	}
+
+	Ok(load(&platform.grid))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 136);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 64);
+	}
+}
@@ -0,0 +1,185 @@
+use aoc_common::{
+	debugger::{self, step_requested, Simulation},
+	dir::Dir,
+	grid_map::GridSet,
+};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::{Grid, Point},
+};
+
+/// The beams of light bouncing around the contraption, all of them moving one tile per step.
+#[derive(Debug, Clone)]
+pub struct Beams<'a> {
+	grid: Grid<'a>,
+	/// Every tile and direction a beam has gone through, to stop beams going around in loops.
+	cycles: GridSet<(Point, Dir)>,
+	dirs_pos: Vec<(Dir, Point)>,
+}
+
+impl<'a> Beams<'a> {
+	pub fn new(grid: Grid<'a>, direction: Dir, start_pos: Point) -> Self {
+		Beams {
+			cycles: GridSet::for_grid(&grid),
+			grid,
+			dirs_pos: vec![(direction, start_pos)],
+		}
+	}
+
+	fn energized(&self) -> GridSet {
+		let mut energized = GridSet::for_grid(&self.grid);
+		energized.extend(self.cycles.iter().map(|(pos, _)| pos));
+		energized
+	}
+}
+
+impl Simulation for Beams<'_> {
+	fn step(&mut self) -> bool {
+		let Beams {
+			grid,
+			cycles,
+			dirs_pos,
+		} = self;
+		if !dirs_pos.iter().any(|&(_, pos)| grid.is_valid_pos(pos)) {
+			return false;
+		}
+
+		for i in (0..dirs_pos.len()).rev() {
+			let (dir, pos) = dirs_pos[i];
+			if !grid.is_valid_pos(pos) || cycles.contains((pos, dir)) {
+				continue;
+			}
+			cycles.insert((pos, dir));
+
+			match grid.get_pos(pos).unwrap() {
+				b'.' => (),
+				b'-' if dir.is_horizontal() => (),
+				b'|' if dir.is_vertical() => (),
+				b'\\' => {
+					dirs_pos[i].0 = if dir.is_horizontal() {
+						dir.turn_right()
+					} else {
+						dir.turn_left()
+					};
+				}
+				b'/' => {
+					dirs_pos[i].0 = if dir.is_horizontal() {
+						dir.turn_left()
+					} else {
+						dir.turn_right()
+					};
+				}
+				b'-' | b'|' => {
+					dirs_pos.remove(i);
+					for new_dir in [dir.turn_left(), dir.turn_right()] {
+						dirs_pos.insert(i, (new_dir, new_dir.step(pos)));
+					}
+
+					continue;
+				}
+				&c => unreachable!("char: {}", c as char),
+			}
+
+			dirs_pos[i].1 = dirs_pos[i].0.step(pos);
+		}
+		for i in (0..dirs_pos.len()).rev() {
+			let (dir, pos) = dirs_pos[i];
+			if !grid.is_valid_pos(pos) || cycles.contains((pos, dir)) {
+				dirs_pos.remove(i);
+			}
+		}
+		true
+	}
+
+	fn render(&self) -> String {
+		let energized = self.energized();
+		let mut res = String::new();
+		for y in 0..self.grid.height() {
+			for x in 0..self.grid.width() {
+				let pos = (x, y);
+				res.push(match self.dirs_pos.iter().find(|&&(_, beam)| beam == pos) {
+					Some((dir, _)) => dir.arrow() as char,
+					None if self.grid[pos] != b'.' => self.grid[pos] as char,
+					None if energized.contains(pos) => '#',
+					None => '.',
+				});
+			}
+			res.push('\n');
+		}
+		res
+	}
+
+	fn counters(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("beams", self.dirs_pos.len().to_string()),
+			("energized", self.energized().len().to_string()),
+		]
+	}
+}
+
+fn laser(grid: Grid<'_>, direction: Dir, start_pos: Point) -> usize {
+	let mut beams = Beams::new(grid, direction, start_pos);
+	while beams.step() {}
+	beams.energized().len()
+}
+
+pub fn parse(input: &str) -> Result<Grid<'_>> {
+	Grid::for_str(input).ok_or_eyre("invalid format")
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+	let grid = parse(input)?;
+	if step_requested() {
+		debugger::run(Beams::new(grid, Dir::Right, (0, 0)))?;
+	}
+	let res = laser(grid, Dir::Right, (0, 0));
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+	let grid = parse(input)?;
+
+	let top = (0..grid.width()).map(|x| (Dir::Down, (x, 0)));
+	let bottom = (0..grid.width()).map(|x| (Dir::Up, (x, grid.height() - 1)));
+	let left = (0..grid.height()).map(|y| (Dir::Right, (0, y)));
+	let right = (0..grid.height()).map(|y| (Dir::Left, (grid.width() - 1, y)));
+
+	let res = top
+		.chain(bottom)
+		.chain(left)
+		.chain(right)
+		.map(|(dir, start_pos)| laser(grid, dir, start_pos))
+		.max()
+		.unwrap();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 46);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 51);
+	}
+}
@@ -0,0 +1,88 @@
+use aoc_common::search::astar;
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::{Grid, PointExt},
+};
+
+pub fn parse(input: &str) -> Result<Grid<'_>> {
+	Grid::for_str(input).ok_or_eyre("invalid format")
+}
+
+fn solve(grid: Grid<'_>, min_moves: i64, max_moves: i64) -> Result<i64> {
+	let start = (0, 0);
+	let end = (grid.width() - 1, grid.height() - 1);
+
+	let get_val = |pos| grid.get_pos(pos).map(|&v| (v - b'0') as i64);
+
+	let neighbors = |&((x, y), (dx, dy)): &_| {
+		let horizontal = (dx == 0)
+			.then(move || {
+				(min_moves..=max_moves)
+					.flat_map(move |dx| [((x + dx, y), (dx, 0)), ((x - dx, y), (-dx, 0))])
+			})
+			.into_iter()
+			.flatten();
+		let vertical = (dy == 0)
+			.then(move || {
+				(min_moves..=max_moves)
+					.flat_map(move |dy| [((x, y + dy), (0, dy)), ((x, y - dy), (0, -dy))])
+			})
+			.into_iter()
+			.flatten();
+
+		horizontal.chain(vertical).filter_map(move |(pos, d)| {
+			let diff = d.map(i64::signum);
+			let cost =
+				std::iter::successors(Some(diff), |prev| (*prev != d).then_some(diff.add(prev)))
+					.map(|d| get_val(d.add(&(x, y))))
+					.try_fold(0, |acc, curr| Some(acc + curr?))?;
+			Some(((pos, diff), cost))
+		})
+	};
+
+	// Every block costs at least 1, so the manhattan distance never overestimates.
+	let heuristic = |&((x, y), _): &_| (end.0 - x) + (end.1 - y);
+
+	astar((start, (0, 0)), neighbors, heuristic, |&(p, _)| p == end)
+		.goal_cost()
+		.ok_or_eyre("no path to the end")
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	solve(parse(input)?, 1, 3)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	solve(parse(input)?, 4, 10)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 102);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 94);
+	}
+}
@@ -0,0 +1,334 @@
+use std::{
+	collections::{HashMap, VecDeque},
+	str::FromStr,
+};
+
+use aoc_common::parse::{split_sections, value};
+use aoc_lib::{
+	color_eyre::eyre::{bail, OptionExt, Report, Result},
+	to_lines,
+};
+
+#[derive(Debug, Clone)]
+pub enum Res {
+	Accept,
+	Reject,
+	Send(String),
+}
+
+impl Res {
+	fn for_str(s: &str) -> Res {
+		match s {
+			"A" => Res::Accept,
+			"R" => Res::Reject,
+			s => Res::Send(s.to_string()),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ValRange {
+	/// Exclusive bounds, every value in range is greater than `gt` and less than `lt`.
+	pub gt: u64,
+	pub lt: u64,
+}
+
+impl ValRange {
+	const MIN: u64 = 1;
+	const MAX: u64 = 4000;
+
+	pub fn new_less_than(val: u64) -> Self {
+		Self {
+			lt: val,
+			..Self::default()
+		}
+	}
+
+	pub fn new_greater_than(val: u64) -> Self {
+		Self {
+			gt: val,
+			..Self::default()
+		}
+	}
+
+	pub fn combine(&self, other: &Self) -> Option<ValRange> {
+		let gt = self.gt.max(other.gt);
+		let lt = self.lt.min(other.lt);
+		if gt >= lt {
+			None
+		} else {
+			Some(ValRange { gt, lt })
+		}
+	}
+
+	pub fn count(&self) -> u64 {
+		self.lt - self.gt - 1
+	}
+
+	pub fn value(&self) -> u64 {
+		assert_eq!(self.gt + 1, self.lt - 1);
+		self.gt + 1
+	}
+
+	pub fn reverse(&self) -> Self {
+		let d = Self::default();
+		// saturating, since conditions like `x<0` are just never true
+		let gt = if self.lt == d.lt {
+			d.gt
+		} else {
+			self.lt.saturating_sub(1)
+		};
+		let lt = if self.gt == d.gt {
+			d.lt
+		} else {
+			self.gt.saturating_add(1)
+		};
+		ValRange { gt, lt }
+	}
+}
+
+impl Default for ValRange {
+	fn default() -> Self {
+		ValRange {
+			gt: Self::MIN - 1,
+			lt: Self::MAX + 1,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Obj {
+	pub x: ValRange,
+	pub m: ValRange,
+	pub a: ValRange,
+	pub s: ValRange,
+}
+
+impl Obj {
+	pub fn combine_prop(self, prop: u8, range: ValRange) -> Option<Obj> {
+		Some(match prop {
+			b'x' => Obj {
+				x: self.x.combine(&range)?,
+				..self
+			},
+			b'm' => Obj {
+				m: self.m.combine(&range)?,
+				..self
+			},
+			b'a' => Obj {
+				a: self.a.combine(&range)?,
+				..self
+			},
+			b's' => Obj {
+				s: self.s.combine(&range)?,
+				..self
+			},
+			_ => return None,
+		})
+	}
+}
+
+/// The property named `name`, one of `x`, `m`, `a` and `s`.
+fn property(name: &str) -> Result<u8> {
+	match name.bytes().next() {
+		Some(prop @ (b'x' | b'm' | b'a' | b's')) if name.len() == 1 => Ok(prop),
+		_ => bail!("invalid property {name:?}"),
+	}
+}
+
+impl FromStr for Obj {
+	type Err = Report;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let mut res = Obj::default();
+		let props = braced(s.trim())?;
+
+		for prop in props.split(',') {
+			let (name, val) = prop.split_once('=').ok_or_eyre("invalid property")?;
+			let val: u64 = value(val)?;
+			if !(ValRange::MIN..=ValRange::MAX).contains(&val) {
+				bail!("rating {val} out of range");
+			}
+			let range = ValRange {
+				gt: val - 1,
+				lt: val + 1,
+			};
+			res = res
+				.combine_prop(property(name)?, range)
+				.ok_or_eyre("invalid property")?;
+		}
+
+		Ok(res)
+	}
+}
+
+/// The part of `s` between the outer `{` and `}`.
+fn braced(s: &str) -> Result<&str> {
+	s.strip_prefix('{')
+		.and_then(|s| s.strip_suffix('}'))
+		.ok_or_eyre("expected `{...}`")
+}
+
+pub type RangeWithTarget = (Option<(u8, ValRange)>, Res);
+fn parse_ins(line: &str) -> Result<(String, Vec<RangeWithTarget>)> {
+	let line = line.trim();
+	let idx = line.find('{').ok_or_eyre("invalid workflow")?;
+	let name = &line[..idx];
+
+	let ins = braced(&line[idx..])?
+		.split(',')
+		.map(|instr| {
+			let Some((cmd, res)) = instr.split_once(':') else {
+				return Ok((None, Res::for_str(instr)));
+			};
+			let f = if let Some((prop, val)) = cmd.split_once('>') {
+				(property(prop)?, ValRange::new_greater_than(value(val)?))
+			} else if let Some((prop, val)) = cmd.split_once('<') {
+				(property(prop)?, ValRange::new_less_than(value(val)?))
+			} else {
+				bail!("invalid condition {cmd:?}")
+			};
+			Ok((Some(f), Res::for_str(res)))
+		})
+		.collect::<Result<_>>()?;
+
+	Ok((name.to_string(), ins))
+}
+
+pub type Workflows = HashMap<String, Vec<RangeWithTarget>>;
+
+/// The workflows by name, and the parts to sort with them.
+pub fn parse(input: &str) -> Result<(Workflows, Vec<Obj>)> {
+	let [ins, objs] = split_sections(input)?;
+
+	let ins = to_lines(ins).map(parse_ins).collect::<Result<_>>()?;
+	let objs = to_lines(objs).map(Obj::from_str).collect::<Result<_>>()?;
+
+	Ok((ins, objs))
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+	let (ins, objs) = parse(input)?;
+
+	let res = objs
+		.into_iter()
+		.filter_map(|obj| {
+			let mut curr = "in";
+
+			'outer: loop {
+				let tests = ins.get(curr).unwrap();
+
+				for (test, target) in tests {
+					if test
+						.map(|(prop, range)| obj.combine_prop(prop, range).is_none())
+						.unwrap_or(false)
+					{
+						continue;
+					}
+					match target {
+						Res::Send(next) => {
+							curr = next;
+							continue 'outer;
+						}
+						Res::Accept => {
+							return Some(
+								obj.x.value() + obj.m.value() + obj.a.value() + obj.s.value(),
+							)
+						}
+						Res::Reject => return None,
+					}
+				}
+				unreachable!()
+			}
+		})
+		.sum();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+	let (ins, _) = parse(input)?;
+
+	let mut queue = VecDeque::new();
+	queue.push_back(("in", Obj::default()));
+
+	let mut res = 0;
+	'outer: while let Some((curr_ins, mut obj)) = queue.pop_front() {
+		for (ins, target) in ins.get(curr_ins).unwrap() {
+			let new_obj = if let Some((prop, range)) = ins {
+				let Some(new_obj) = obj.combine_prop(*prop, *range) else {
+					continue 'outer;
+				};
+				let Some(next_obj) = obj.combine_prop(*prop, range.reverse()) else {
+					continue 'outer;
+				};
+				obj = next_obj;
+				new_obj
+			} else {
+				obj
+			};
+
+			match target {
+				Res::Accept => {
+					res += new_obj.x.count()
+						* new_obj.m.count()
+						* new_obj.a.count()
+						* new_obj.s.count()
+				}
+				Res::Reject => continue,
+				Res::Send(next) => queue.push_back((next.as_str(), new_obj)),
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 19114);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 167409079868000);
+	}
+
+	#[test]
+	fn invalid() {
+		for input in [
+			"in{<5:A,R}\n\n{x=1,m=1,a=1,s=1}",
+			"in{q<5:A,R}\n\n{x=1,m=1,a=1,s=1}",
+			"in{A}\n\n{=1,m=1,a=1,s=1}",
+			"in{A}\n\n{x=0,m=1,a=1,s=1}",
+		] {
+			assert!(part1(input).is_err(), "{input:?}");
+		}
+		// never true, but not an error either
+		assert!(part2("in{x<0:R,A}\n\n{x=1,m=1,a=1,s=1}").is_ok());
+	}
+}
@@ -0,0 +1,400 @@
+use std::{
+	collections::{HashMap, VecDeque},
+	ops::BitOr,
+};
+
+use aoc_common::graph::{dot_requested, Graph};
+use aoc_lib::{
+	color_eyre::eyre::{bail, Result},
+	to_lines,
+};
+
+const NONEXISTENT_MODULE: usize = 9999;
+const RX_MODULE: usize = 99999;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Mod {
+	Broadcaster,
+	FlipFlop,
+	Conjunction(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse {
+	Low,
+	High,
+}
+
+/// A module's type, the modules sending pulses to it, and the ones it sends pulses to.
+pub type Module = (Mod, Vec<usize>, Vec<usize>);
+
+pub fn parse(input: &str) -> (Vec<Module>, usize, usize) {
+	let map: HashMap<_, _> = to_lines(input)
+		.enumerate()
+		.map(|(i, line)| {
+			let (from, to) = line.split_once(" -> ").unwrap();
+			let targets: Vec<_> = to.split(", ").collect();
+			(&from[1..], (i, targets))
+		})
+		.collect();
+
+	assert!(map.len() <= 64);
+
+	let mut res = Vec::with_capacity(map.len());
+	let mut start_idx = 0;
+	let mut rx_idx = 0;
+
+	for line in to_lines(input) {
+		let (name, _) = line.split_once(" -> ").unwrap();
+
+		let targets: Vec<_> = map
+			.get(&name[1..])
+			.unwrap()
+			.1
+			.iter()
+			.map(|target| {
+				if *target == "rx" {
+					rx_idx = res.len();
+					RX_MODULE
+				} else {
+					map.get(target)
+						.map(|target| target.0)
+						.unwrap_or(NONEXISTENT_MODULE)
+				}
+			})
+			.collect();
+
+		let typ = if name == "broadcaster" {
+			start_idx = res.len();
+			Mod::Broadcaster
+		} else {
+			match name.as_bytes()[0] {
+				b'%' => Mod::FlipFlop,
+				b'&' => Mod::Conjunction(0),
+				_ => unreachable!(),
+			}
+		};
+
+		res.push((typ, Vec::new(), targets));
+	}
+
+	// Plug all inputs into their outputs so the outputs remember where the pulses are coming from.
+	// This whole ordeal is so that the borrow checker doesn't complain about mutating `res`
+	// while it's being borrowed for the iteration.
+	// Yes, it could be done better, no I do not care.
+	for i in 0..res.len() {
+		for target_idx in 0..res[i].2.len() {
+			let j = res[i].2[target_idx];
+			if matches!(j, NONEXISTENT_MODULE | RX_MODULE) {
+				continue;
+			}
+			res[j].1.push(i);
+		}
+	}
+
+	(res, start_idx, rx_idx)
+}
+
+fn module_name(input: &str, idx: usize) -> &str {
+	let line = to_lines(input).nth(idx).unwrap();
+	let (name, _) = line.split_once(" -> ").unwrap();
+	name.trim_start_matches(['%', '&'])
+}
+
+/// Every module whose pulses end up reaching `idx`, up to the broadcaster, as a bitset.
+fn upstream(arr: &[Module], idx: usize, start_idx: usize) -> u64 {
+	let mut res = 1 << idx;
+	let mut stack = vec![idx];
+	while let Some(idx) = stack.pop() {
+		for &input in &arr[idx].1 {
+			if input != start_idx && res & (1 << input) == 0 {
+				res |= 1 << input;
+				stack.push(input);
+			}
+		}
+	}
+	res
+}
+
+/// Checks that rx is fed by a single conjunction, whose inputs are each driven
+/// by a separate part of the network, and returns how many times the button
+/// has to be pressed at most before each of those sub-counters has to send a high pulse.
+fn sub_counters(
+	input: &str,
+	arr: &[Module],
+	start_idx: usize,
+	rx_idx: usize,
+) -> Result<Vec<usize>> {
+	let feeders = arr
+		.iter()
+		.filter(|(_, _, outputs)| outputs.contains(&RX_MODULE))
+		.count();
+	if feeders != 1 {
+		bail!("rx should be fed by a single conjunction, but it has {feeders} inputs");
+	}
+	if !matches!(arr[rx_idx].0, Mod::Conjunction(_)) {
+		bail!(
+			"{}, which feeds rx, isn't a conjunction",
+			module_name(input, rx_idx)
+		);
+	}
+
+	let mut seen = 0;
+	arr[rx_idx]
+		.1
+		.iter()
+		.map(|&idx| {
+			let modules = upstream(arr, idx, start_idx);
+			if seen & modules != 0 {
+				bail!(
+					"the sub-counter ending in {} isn't independent, it shares modules with another one",
+					module_name(input, idx)
+				);
+			}
+			seen |= modules;
+			// a sub-counter can't go through more states than its flip-flops have
+			let flip_flops = (0..arr.len())
+				.filter(|&i| modules & (1 << i) != 0 && matches!(arr[i].0, Mod::FlipFlop))
+				.count();
+			Ok(1 << flip_flops)
+		})
+		.collect()
+}
+
+/// Renders the module network in DOT format.
+/// Flip-flops are drawn as boxes, conjunctions as diamonds.
+fn module_graph_dot(input: &str) -> String {
+	let mut graph = Graph::directed();
+	let mut kinds = HashMap::new();
+
+	for line in to_lines(input) {
+		let (from, to) = line.split_once(" -> ").unwrap();
+		let name = from.trim_start_matches(['%', '&']);
+		kinds.insert(name, from.as_bytes()[0]);
+		for target in to.split(", ") {
+			graph.connect(name, target, ());
+		}
+	}
+
+	let dot = graph
+		.dot()
+		.node_attrs(|_, name| {
+			match kinds.get(name) {
+				Some(b'%') => "shape=box",
+				Some(b'&') => "shape=diamond",
+				Some(_) => "shape=doublecircle",
+				None => "shape=plaintext",
+			}
+			.to_string()
+		})
+		.to_string();
+	dot
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+	let (mut arr, start_idx, _) = parse(input);
+	let mut state = 0u64;
+	let mut total_low = 0;
+	let mut total_high = 0;
+
+	for _ in 0..1000 {
+		let mut queue = VecDeque::new();
+		queue.push_back((start_idx, Pulse::Low, NONEXISTENT_MODULE));
+
+		while let Some((idx, pulse, from)) = queue.pop_front() {
+			match pulse {
+				Pulse::Low => total_low += 1,
+				Pulse::High => total_high += 1,
+			}
+			if matches!(idx, NONEXISTENT_MODULE | RX_MODULE) {
+				continue;
+			}
+			let (typ, inputs, outputs) = &mut arr[idx];
+
+			match typ {
+				Mod::Broadcaster => outputs
+					.iter()
+					.for_each(|&target_idx| queue.push_back((target_idx, pulse, idx))),
+				Mod::FlipFlop => {
+					if pulse == Pulse::High {
+						continue;
+					}
+					let mask = 1 << idx;
+					state ^= mask;
+					let is_off = state & mask == 0;
+					outputs.iter().for_each(|&target_idx| {
+						queue.push_back((
+							target_idx,
+							if is_off { Pulse::Low } else { Pulse::High },
+							idx,
+						))
+					});
+				}
+				Mod::Conjunction(ref mut mem) => {
+					let all_inputs = inputs.iter().map(|&idx| 1 << idx).fold(0, u64::bitor);
+					let mask = 1 << from;
+					if pulse == Pulse::High {
+						*mem |= mask;
+					} else {
+						*mem &= !mask;
+					}
+					let all_were_on = *mem == all_inputs;
+					outputs.iter().for_each(|&target_idx| {
+						queue.push_back((
+							target_idx,
+							if all_were_on { Pulse::Low } else { Pulse::High },
+							idx,
+						))
+					});
+				}
+			}
+		}
+	}
+
+	Ok(total_low * total_high)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+	let (arr, start_idx, rx_idx) = parse(input);
+
+	if dot_requested() {
+		// the sub-counters feeding into rx are easy to spot once the network is drawn
+		eprintln!("{}", module_graph_dot(input));
+	}
+
+	// This only works because we need to notice that those inputs don't depend on each other in any way.
+	// I wasn't smart enough to figure this out myself so thanks to @tumdum and @zsacul.
+	let max_presses = sub_counters(input, &arr, start_idx, rx_idx)?;
+	let res = arr[rx_idx]
+		.1
+		.iter()
+		.zip(max_presses)
+		.map(|(&counter, max_presses)| {
+			let mut arr = arr.clone();
+			let mut state = 0u64;
+
+			for i in 1..=max_presses {
+				let mut queue = VecDeque::new();
+				queue.push_back((start_idx, Pulse::Low, NONEXISTENT_MODULE));
+
+				while let Some((idx, pulse, from)) = queue.pop_front() {
+					if matches!(idx, NONEXISTENT_MODULE | RX_MODULE) {
+						continue;
+					}
+					if idx == rx_idx && from == counter && pulse == Pulse::High {
+						return Ok(i);
+					}
+					let (typ, inputs, outputs) = &mut arr[idx];
+
+					match typ {
+						Mod::Broadcaster => outputs
+							.iter()
+							.for_each(|&target_idx| queue.push_back((target_idx, pulse, idx))),
+						Mod::FlipFlop => {
+							if pulse == Pulse::High {
+								continue;
+							}
+							let mask = 1 << idx;
+							state ^= mask;
+							let is_off = state & mask == 0;
+							outputs.iter().for_each(|&target_idx| {
+								queue.push_back((
+									target_idx,
+									if is_off { Pulse::Low } else { Pulse::High },
+									idx,
+								))
+							});
+						}
+						Mod::Conjunction(ref mut mem) => {
+							let all_inputs = inputs.iter().map(|&idx| 1 << idx).fold(0, u64::bitor);
+							let mask = 1 << from;
+							if pulse == Pulse::High {
+								*mem |= mask;
+							} else {
+								*mem &= !mask;
+							}
+							let all_were_on = *mem == all_inputs;
+							outputs.iter().for_each(|&target_idx| {
+								queue.push_back((
+									target_idx,
+									if all_were_on { Pulse::Low } else { Pulse::High },
+									idx,
+								))
+							});
+						}
+					}
+				}
+			}
+			bail!(
+				"the sub-counter ending in {} never sends a high pulse in {max_presses} presses",
+				module_name(input, counter)
+			)
+		})
+		.collect::<Result<Vec<_>>>()?
+		.into_iter()
+		.fold(1, lcm);
+
+	fn gcd(a: usize, b: usize) -> usize {
+		if b == 0 {
+			a
+		} else {
+			gcd(b, a % b)
+		}
+	}
+
+	fn lcm(a: usize, b: usize) -> usize {
+		a * b / gcd(a, b)
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static INPUT: &str = include_str!("../inputs/day20/input");
+
+	static EX_INPUT_1: &str = r#"
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+"#;
+
+	const EX_INPUT_1_SOL: u64 = 32000000;
+
+	static EX_INPUT_2: &str = r#"
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+"#;
+
+	const EX_INPUT_2_SOL: u64 = 11687500;
+
+	#[test]
+	fn part1_example_2() {
+		assert_eq!(part1(EX_INPUT_2).unwrap(), EX_INPUT_2_SOL);
+	}
+
+	#[test]
+	fn part2_input() {
+		assert_eq!(part2(INPUT).unwrap(), 244465191362269);
+	}
+
+	#[test]
+	fn part1_example_1() {
+		assert_eq!(part1(EX_INPUT_1).unwrap(), EX_INPUT_1_SOL);
+	}
+
+	#[test]
+	fn part2_not_a_conjunction() {
+		let err = part2("broadcaster -> a\n%a -> rx\n")
+			.unwrap_err()
+			.to_string();
+		assert!(err.contains("isn't a conjunction"), "{err}");
+	}
+}
@@ -0,0 +1,191 @@
+use aoc_common::sparse_grid::SparseGrid;
+use aoc_lib::{
+	color_eyre::eyre::{bail, OptionExt, Result},
+	grid::{Grid, Point},
+};
+
+/// The garden, and where the elf starts in it.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Point)> {
+	let grid = Grid::for_str(input).ok_or_eyre("invalid format")?;
+
+	let start_idx = input.trim().find('S').ok_or_eyre("no starting position")?;
+	let start_pos = grid.idx_to_pos(start_idx).ok_or_eyre("invalid format")?;
+
+	Ok((grid, start_pos))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+	#[cfg(test)]
+	const TARGET_STEPS: usize = 6;
+	#[cfg(not(test))]
+	const TARGET_STEPS: usize = 64;
+
+	let (grid, start_pos) = parse(input)?;
+
+	let (dist, _) = aoc_lib::algo::dijkstra(start_pos, |&pos| {
+		grid.orthogonal_pos(pos)
+			.filter(|&pos| grid[pos] != b'#')
+			.map(|pos| (pos, 1))
+	});
+
+	let modulo = TARGET_STEPS & 1;
+	let res = dist
+		.into_values()
+		.filter(|&d| d <= TARGET_STEPS && d & 1 == modulo)
+		.count();
+
+	Ok(res)
+}
+
+/// Checks the properties of the garden which the extrapolation in `part2` relies on.
+fn check_garden(grid: &Grid, start_pos: Point) -> Result<()> {
+	let (width, height) = (grid.width(), grid.height());
+	if width != height {
+		bail!("the garden isn't square, it's {width}x{height}");
+	}
+	if width % 2 == 0 || start_pos != (width / 2, height / 2) {
+		bail!("the start at {start_pos:?} isn't in the center of the garden");
+	}
+	let lanes = (0..width).flat_map(|i| {
+		[
+			(i, start_pos.1),
+			(start_pos.0, i),
+			(i, 0),
+			(i, height - 1),
+			(0, i),
+			(width - 1, i),
+		]
+	});
+	for pos in lanes {
+		if grid[pos] == b'#' {
+			bail!("the lanes through the start and along the edges aren't clear, there's a rock at {pos:?}");
+		}
+	}
+	Ok(())
+}
+
+// Yes, this solution is suboptimal.
+// Yes, I do not care in the slightest.
+//
+// To understand this solution we first need to notice that the path to the center
+// is completely unobscured.
+// Wait, center? Yes, the starting position is also perfectly centered
+// (which is what makes this particular solution possible).
+// Basically, because the view is unobscured, and because the start is in the center,
+// every time we reach a multiple of the starting posisiton,
+// we will have added to the amount of possible paths we can reach a multiple of our map's size.
+// (Oh, right, the map is a square, that also contributes to the symmetry)
+//
+// With this we can notice that the amount of steps each "loop" adds is constant,
+// i.e. the acceleration of the number of steps at any given target step count is equal to
+// target_steps % grid_width.
+// Let's call target_steps % grid_width n. What I'm doing is taking points at n, n + width, n + width * 2
+// And based on that I can extrapolate what the number of reachable steps will be at n + width * K for any K.
+// The *actual* solution is a bit more complicated
+// since we need to take the fact whether target steps is even or odd into account,
+// and it doesn't actually work for all target steps or target steps low enough,
+// but at this point I really don't care so I'm finishing this monologue, pushing to github, and going to sleep.
+// Goodnight, and hopefully the challenge that's releasing in 5 hours is more fun than whatever this was.
+pub fn part2(input: &str) -> Result<i64> {
+	const TARGET_STEPS: i64 = 26501365;
+	let (grid, start_pos) = parse(input)?;
+	check_garden(&grid, start_pos)?;
+
+	let garden = SparseGrid::tiled(grid);
+
+	let mut res = 0;
+	let modulo = TARGET_STEPS & 1;
+
+	// the reachable plots of the last two steps, marked the same way the puzzle does
+	let mut prev_points = SparseGrid::new();
+	let mut curr_points = SparseGrid::new();
+	curr_points.set(start_pos, b'O');
+	let mut starts_reached = 0;
+	let mut trends = Vec::new();
+
+	for step in 0.. {
+		if step & 1 == modulo {
+			res += curr_points.len() as i64;
+			trends.push(res);
+			if curr_points
+				.iter()
+				.any(|(pos, _)| garden.base_pos(pos) == Some(start_pos))
+			{
+				if starts_reached == 3 {
+					break;
+				}
+				starts_reached += 1;
+			}
+		}
+
+		let mut next_points = SparseGrid::new();
+		for (pos, _) in curr_points.iter() {
+			for next_pos in garden.orthogonal_pos(pos) {
+				if !prev_points.contains(next_pos) && garden.get(next_pos) != Some(b'#') {
+					next_points.set(next_pos, b'O');
+				}
+			}
+		}
+
+		prev_points = curr_points;
+		curr_points = next_points;
+	}
+
+	let steps_to_start = grid.width();
+
+	let need = TARGET_STEPS % steps_to_start;
+	let need = if need >= steps_to_start / 2 {
+		need - steps_to_start
+	} else {
+		need
+	};
+	let times = TARGET_STEPS / steps_to_start / 2;
+	let steps_at_nth = |n| {
+		let idx = (steps_to_start * n + need) / 2;
+		trends[idx as usize]
+	};
+
+	let needed_points: Vec<_> = (0..3).map(|i| steps_at_nth(i * 2 + modulo)).collect();
+	let d: Vec<_> = needed_points.windows(2).map(|d| d[1] - d[0]).collect();
+	let dd = d[1] - d[0];
+	let res = (0..times).map(|i| d[0] + dd * i).sum::<i64>() + needed_points[0];
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static INPUT: &str = include_str!("../inputs/day21/input");
+
+	static EX_INPUT: &str = r#"
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 16);
+	}
+
+	#[test]
+	fn part2_input() {
+		assert_eq!(part2(INPUT).unwrap(), 616583483179597);
+	}
+
+	#[test]
+	fn part2_obstructed_example() {
+		let err = part2(EX_INPUT).unwrap_err().to_string();
+		assert!(err.contains("aren't clear"), "{err}");
+	}
+}
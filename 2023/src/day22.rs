@@ -0,0 +1,110 @@
+use aoc_common::vec3::{Aabb3, Vec3};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	to_lines,
+};
+
+/// Every brick of the snapshot.
+pub fn parse(input: &str) -> Result<Vec<Aabb3>> {
+	to_lines(input)
+		.map(|line| {
+			let (from, to) = line.split_once('~').ok_or_eyre("invalid format")?;
+			Ok(Aabb3::new(from.parse()?, to.parse()?))
+		})
+		.collect()
+}
+
+fn get_blocks(mut blocks: Vec<Aabb3>) -> (Vec<Vec<usize>>, usize) {
+	blocks.sort_unstable_by_key(|block| block.min.z);
+
+	let mut supported_by = Vec::new();
+
+	for i in 0..blocks.len() {
+		loop {
+			let maybe = blocks[i].translate(-Vec3::Z);
+			let collisions: Vec<_> = blocks
+				.iter()
+				.enumerate()
+				.take(i)
+				.filter_map(|(i, other)| maybe.intersects(other).then_some(i))
+				.collect();
+
+			if blocks[i].min.z > 1 && collisions.is_empty() {
+				blocks[i] = maybe;
+			} else {
+				supported_by.push(collisions);
+				break;
+			}
+		}
+	}
+
+	(supported_by, blocks.len())
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+	let (supported_by, block_cnt) = get_blocks(parse(input)?);
+
+	// for each block make sure that blocks that depend on it have more than one supporter
+	let res = (0..block_cnt)
+		.filter(|&i| {
+			supported_by
+				.iter()
+				.skip(i + 1)
+				.filter(|sup| sup.contains(&i))
+				.all(|sup| sup.len() > 1)
+		})
+		.count();
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+	let (supported_by, block_cnt) = get_blocks(parse(input)?);
+
+	// for each block count how many would fall if it was removed,
+	// and then how many would fall if those were also removed,
+	// and then how many would fall if those were also removed,
+	// ...
+	let res = (0..block_cnt)
+		.map(|i| {
+			supported_by
+				.iter()
+				.enumerate()
+				.skip(i + 1)
+				.fold(vec![i], |mut fallen, (j, sup)| {
+					if !sup.is_empty() && sup.iter().all(|s| fallen.contains(s)) {
+						fallen.push(j);
+					}
+					fallen
+				})
+				.len() - 1
+		})
+		.sum();
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 5);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 7);
+	}
+}
@@ -0,0 +1,216 @@
+use std::{
+	collections::BinaryHeap,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		mpsc::channel,
+		Arc,
+	},
+	thread,
+	time::Duration,
+};
+
+use aoc_common::grid_map::{GridMap, GridSet};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	grid::{Grid, Point},
+};
+
+pub fn parse(input: &str) -> Result<Grid<'_>> {
+	Grid::for_str(input).ok_or_eyre("invalid format")
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	let grid = parse(input)?;
+	let mut dist = GridMap::for_grid(&grid, None);
+	let mut queue = BinaryHeap::new();
+
+	let start = (1, 0);
+	let end = (grid.width() - 2, grid.height() - 1);
+
+	dist[start] = Some(0);
+	queue.push((0, start, start));
+
+	let valid_step = |step_from: Point, step_to: Point| match grid[step_from] {
+		b'>' => step_from.0 < step_to.0,
+		b'<' => step_from.0 > step_to.0,
+		b'v' => step_from.1 < step_to.1,
+		b'^' => step_from.1 > step_to.1,
+		_ => true,
+	};
+
+	while let Some((d, curr, came_from)) = queue.pop() {
+		let neighbors = grid
+			.orthogonal_pos(curr)
+			.filter(|&pos| grid[pos] != b'#' && pos != came_from && valid_step(curr, pos));
+		for neighbor in neighbors {
+			let d = d + 1;
+			if dist[neighbor].is_none_or(|curr| curr < d) {
+				queue.push((d, neighbor, curr));
+				dist[neighbor] = Some(d);
+			}
+		}
+	}
+
+	Ok(dist[end].unwrap())
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let grid = parse(input)?;
+
+	let start = (1, 0);
+	let end = (grid.width() - 2, grid.height() - 1);
+
+	let (tx, rx) = channel();
+	let should_stop = Arc::new(AtomicBool::new(false));
+
+	let res = thread::scope(|s| {
+		let t = s.spawn(|| {
+			// moved in, so the channel closes once every path has been explored
+			let tx = tx;
+			let mut largest = 0;
+			// Every junction branches off into paths to explore later,
+			// each remembering the tiles walked on to get to it.
+			let mut stack = vec![(start, 0, GridSet::for_grid(&grid))];
+			while let Some((mut curr, mut d, mut came_from)) = stack.pop() {
+				loop {
+					if should_stop.load(Ordering::Acquire) {
+						return;
+					}
+					if curr == end {
+						if d > largest {
+							largest = d;
+							tx.send(d).unwrap();
+						}
+						break;
+					}
+					came_from.insert(curr);
+
+					let neighbors: Vec<_> = grid
+						.orthogonal_pos(curr)
+						.filter(|&pos| !came_from.contains(pos) && grid[pos] != b'#')
+						.collect();
+
+					match neighbors[..] {
+						[] => break,
+						[neighbor] => {
+							curr = neighbor;
+							d += 1
+						}
+						_ => {
+							// pushed in reverse, so they're explored in order
+							for &neighbor in neighbors[1..].iter().rev() {
+								stack.push((neighbor, d + 1, came_from.clone()));
+							}
+							stack.push((neighbors[0], d + 1, came_from));
+							break;
+						}
+					}
+				}
+			}
+		});
+		let mut largest = 0;
+
+		// Let's just say that if we don't receive a higher value for more than three minutes we found the answer
+		//                              ░▒▒▒▒▒▒
+		//                           ░▒▒░░░░░░░░░▒░
+		//                          ░▒░░░░░░░░░░░░░░
+		//                          ▒░░░░░░░░░░░░░░░
+		//                          ▒▒░░░░░░░░░░░░░░
+		//                         ▒▒▒░░░░░░░░░░░░░▒
+		//              ░▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒░░░░░░░▒▒▒
+		//          ▒▒░░░░▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒
+		//       ▒▒▒▒▒▒▒▒▒▒░▒░▒░▒▒▒▒▒▒░░░░▒▒▒▒▒
+		//     ▒▒▒▒▒▒░▒░░░░░░░░░░░░░▒░░░░░░░░▒▒     ▒▒▒▒▒▒▒▒▓▓▓▒▒▒▒▒▒▒
+		//   ░▒▒▒▒░░░░░░░░░░░░░░░░░░░░░░░░░░░░▒▒▒▒▒▒▒▒░░░▓▒░░░░░░░░░░▒▒▒▒▒▒
+		//  ▒▒▒▒▒░▒░░░░░░░░░░▒▒░░░░░░░░░░░░░░░░░▒▒░░░░░░░░░░░░░░░░░░░░░░░▓▓▓▒▒
+		//  ▒▒▒▒░▒░░░░░░░░░░░░░░░░░▒▒░░░░░░░░░░░▒▒░░░░░░░░░░░░░░░░░░░░░░░░░▒▓▒▒▒
+		// ▒▒▒▒░░░░░░░░░░░░░░░░░░░░░░░░▒░░░▒▒░░░░▒░░░░░▒▒██▒▓█▒░░░░░░░░░░░░░░▓▒▒▒▒
+		// ▒▒▒▒░▒░░░░░░░░░░░░░░░░░░░░░░░░░░▒░▒▒▒▒░░░░░▒█░░░░░░░░▒░░░░▒▒▒▒▒▒▒░░░▒▒▒▒▒
+		// ▒▒▒▒░░░░░░░░░░░░░░░░░░░░░░░░░░░░▒░▒▒▒▒░░░░▒█░ ▓ ▒▒█ ░█░░░▒█░░░░░░█▒░░▒▒▒▒▒
+		//  ▒▒▒▒░░░░░░░░░░░▒░░░░░░░░░░░░░░░░░▒▒░░░░▒▒░▓░█▒███▒ ░▒░░░█░░▒▒█   ░░░░▒▒▒▒▒
+		//  ▒▒▒▒▒░▒░░░░░░░░░░░░░▒░░░░░░░░░░░░░▒▒░░░░░▒▒▒▒█▓▒▓ ░░▒░░░▒████▒█  ░█▒▒▒▒▒▒▒
+		//   ▒▒▒▒▒░░░░░░░░░░░░░░░░░▒░░░░░░░░░▒▒░▒▒▒▒░▒▒▒░░░░░░░▒░░░░░▓█▒▒▓  ░░▓▒▒▒▒▒▒▒▒
+		//     ▒▒▒▒▒░▒░░░░░░░░░░░░░░░▒▒▒▒▒▒▒▒░░░░░░░░░░░░░░░▒░░░░░░░░░░▓░░░░▒▒▒▒░▒▒▒▒▒▒
+		//       ▒▒▒▒▒▒▒░▒░░░░░░░░░░░▒▒▒▒▒▒▒▒░░░░░░░░░▓░░░░░▒░░░░░░░░░░░▒░▒▒▒▒▒▒▒▒▒▒▒▒▒
+		//          ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒░▒░░░░░░░░░█░░▒░░░░░░░░░░░░░░░░░░▒▒░▒▒▒▒▒▒▒
+		//              ░▒▒▒▒▒▒▒▒▒▒▒░▒░░▒▒▒▒▒░▒░░░░░░░░▒██░░░░░░▓▒▒▒▒▒▒▒▒▒▒░░▒▒▒▒▒▒▒▒▒
+		//                           ▒▒░░░▒▒▒▒▒░▒░░░░░░░████▓   ░░░░░██░░░░░▒░▒▒▒▒▒▒▒░
+		//                            ▒▒░░░░▒▒▒▒░▒░░░░░░░▓▒███████████░░░▒░▒▒▒▒▒▒▒▒▒░
+		//                             ░▒░░░░░▒▒▒▒▒░░░░░░░▒▓▓▓▒██████▒░▒░▒▒▒▒▒▒▒▒▒▒
+		//                               ▒▒░░░░░▒▒▒▒▒▒▒░▒░░░▒▓▓▓▓██▒░▒▒▒▒▒▒▒▒▒▒▒▒▒
+		//                                 ▒▒░░░░░░▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒
+		//                                    ▒▒░░░░░▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒
+		//                                       ░▒▒░░░░▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒░
+		//                                             ░▒▒▒▒▒▒▒▒▒▒▒
+		while let Ok(dist) = rx.recv_timeout(Duration::from_secs(180)) {
+			largest = dist;
+		}
+		should_stop.store(true, Ordering::Release);
+		t.join().unwrap();
+		largest
+	});
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+	use aoc_common::{maze, rng::Rng};
+
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 94);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 154);
+	}
+
+	#[test]
+	fn random_trails() {
+		for seed in 0..10 {
+			// without any loops there's only one way to the exit, slippery or not
+			let trails = maze::slopes(21, 21, 0.0, &mut Rng::new(seed));
+			assert_eq!(
+				part1(&trails).unwrap(),
+				part2(&trails).unwrap(),
+				"seed {seed}"
+			);
+
+			let trails = maze::slopes(21, 21, 0.1, &mut Rng::new(seed));
+			assert!(
+				part1(&trails).unwrap() <= part2(&trails).unwrap(),
+				"seed {seed}"
+			);
+		}
+	}
+}
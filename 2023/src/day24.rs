@@ -0,0 +1,135 @@
+use aoc_common::{parse::pair, vec3::Vec3};
+use aoc_lib::{color_eyre::eyre::Result, to_lines};
+use z3::{
+	ast::{Ast, Int},
+	Config, Context, SatResult, Solver,
+};
+
+/// Every hailstone's position and velocity.
+pub fn parse(input: &str) -> Result<Vec<(Vec3, Vec3)>> {
+	to_lines(input).map(|line| pair(line, "@")).collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+	end: bool,
+	vertex: (f64, f64),
+	a: f64,
+	b: f64,
+}
+
+impl Line {
+	fn goes_to_x(&self, x: f64) -> bool {
+		if self.end {
+			x <= self.vertex.0
+		} else {
+			x >= self.vertex.0
+		}
+	}
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+	#[cfg(test)]
+	const MIN_POS: f64 = 7.;
+	#[cfg(test)]
+	const MAX_POS: f64 = 27.;
+
+	#[cfg(not(test))]
+	const MIN_POS: f64 = 200000000000000.;
+	#[cfg(not(test))]
+	const MAX_POS: f64 = 400000000000000.;
+
+	let lines: Vec<_> = parse(input)?
+		.into_iter()
+		.map(|(pos, vel)| {
+			let (x, y) = (pos.x as f64, pos.y as f64);
+			let (dx, dy) = (vel.x as f64, vel.y as f64);
+			let a = dy / dx;
+			let b = y - a * x;
+			let end = dx < 0.;
+			Line {
+				end,
+				vertex: (x, y),
+				a,
+				b,
+			}
+		})
+		.collect();
+
+	let mut res = 0;
+
+	for i in 0..lines.len() - 1 {
+		let a = lines[i];
+		for j in i + 1..lines.len() {
+			let b = lines[j];
+
+			if a.a == b.a {
+				if a.b == b.b {
+					todo!()
+				}
+			} else {
+				let range = MIN_POS..=MAX_POS;
+				let x = (b.b - a.b) / (a.a - b.a);
+				let y = a.a * x + a.b;
+				if range.contains(&x) && range.contains(&y) && a.goes_to_x(x) && b.goes_to_x(x) {
+					res += 1;
+				}
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+	let cfg = Config::new();
+	let ctx = Context::new(&cfg);
+	let s = Solver::new(&ctx);
+
+	let x = Int::new_const(&ctx, "x");
+	let y = Int::new_const(&ctx, "y");
+	let z = Int::new_const(&ctx, "z");
+	let dx = Int::new_const(&ctx, "dx");
+	let dy = Int::new_const(&ctx, "dy");
+	let dz = Int::new_const(&ctx, "dz");
+
+	for (i, (pos, vel)) in parse(input)?.into_iter().enumerate() {
+		let t = Int::new_const(&ctx, format!("t_{i}").as_str());
+
+		s.assert(&(&x + &dx * &t)._eq(&(pos.x + vel.x * &t)));
+		s.assert(&(&y + &dy * &t)._eq(&(pos.y + vel.y * &t)));
+		s.assert(&(&z + &dz * &t)._eq(&(pos.z + vel.z * &t)));
+	}
+
+	assert_eq!(s.check(), SatResult::Sat);
+
+	let m = s.get_model().unwrap();
+	let x = m.get_const_interp(&x).unwrap().as_i64().unwrap();
+	let y = m.get_const_interp(&y).unwrap().as_i64().unwrap();
+	let z = m.get_const_interp(&z).unwrap().as_i64().unwrap();
+
+	Ok(x + y + z)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 2);
+	}
+
+	#[test]
+	fn part2_example() {
+		assert_eq!(part2(EX_INPUT).unwrap(), 47);
+	}
+}
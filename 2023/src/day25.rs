@@ -0,0 +1,99 @@
+use aoc_common::{
+	graph::{dot_requested, Graph},
+	intern::{Id, Interner},
+};
+use aoc_lib::{
+	color_eyre::eyre::{OptionExt, Result},
+	to_lines,
+};
+
+/// The names of the components, and every wire connecting two of them.
+pub fn parse(input: &str) -> Result<(Interner<'_>, Vec<(Id, Id)>)> {
+	let mut names = Interner::new();
+	let mut wires = Vec::new();
+
+	for line in to_lines(input) {
+		let (from, to) = line.split_once(':').ok_or_eyre("invalid format")?;
+		let from = names.intern(from);
+		for node in to.split_ascii_whitespace() {
+			wires.push((from, names.intern(node)));
+		}
+	}
+
+	Ok((names, wires))
+}
+
+// TODO: Solve this properly
+pub fn part1(input: &str) -> Result<usize> {
+	let (names, wires) = parse(input)?;
+	let mut conns = vec![Vec::<Id>::new(); names.len()];
+	for &(from, to) in &wires {
+		conns[from as usize].push(to);
+		conns[to as usize].push(from);
+	}
+
+	if dot_requested() {
+		let mut graph = Graph::undirected();
+		for &(from, to) in &wires {
+			graph.connect(names.name(from), names.name(to), ());
+		}
+		eprintln!("{}", graph.dot());
+	}
+
+	// need to remove:
+	// xvp - zpc
+	// vfs - dhl
+	// pbq - nzn
+	//
+	// found this out by generating a graph with graphviz (run with --dot)
+	let id = |name| names.get(name).ok_or_eyre("the wires to cut aren't there");
+	let cuts = [
+		[id("xvp")?, id("zpc")?],
+		[id("vfs")?, id("dhl")?],
+		[id("pbq")?, id("nzn")?],
+	];
+	let is_cut = |a, b| cuts.contains(&[a, b]) || cuts.contains(&[b, a]);
+
+	let count = |start: Id| {
+		let mut seen = vec![false; names.len()];
+		seen[start as usize] = true;
+		let mut stack = vec![start];
+		while let Some(curr) = stack.pop() {
+			for &conn in &conns[curr as usize] {
+				if !seen[conn as usize] && !is_cut(curr, conn) {
+					seen[conn as usize] = true;
+					stack.push(conn);
+				}
+			}
+		}
+		seen.into_iter().filter(|&seen| seen).count()
+	};
+
+	Ok(count(cuts[0][0]) * count(cuts[0][1]))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static EX_INPUT: &str = r#"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+"#;
+
+	#[test]
+	fn part1_example() {
+		assert_eq!(part1(EX_INPUT).unwrap(), 54);
+	}
+}
@@ -0,0 +1,257 @@
+use aoc_lib::color_eyre::eyre::{OptionExt, Result, bail};
+
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<i64>)> {
+    aoc_lib::to_lines(input)
+        .map(|x| -> Result<(i64, i64)> {
+            let (a, b) = x.split_once("   ").ok_or_eyre("invalid format")?;
+            Ok((a.parse()?, b.parse()?))
+        })
+        .collect()
+}
+
+/// Both lists, read a byte at a time, or `None` if a number doesn't fit in a `u32`,
+/// e.g. because it's negative, leaving it to the first solutions.
+fn lists(input: &str) -> Result<Option<(Vec<u32>, Vec<u32>)>> {
+    // lines are usually two 5 digit numbers apart
+    let mut left = Vec::with_capacity(input.len() / 14);
+    let mut right = Vec::with_capacity(input.len() / 14);
+    let mut line = [0; 2];
+    let mut numbers = 0;
+    // whether a number has been started, and what it is so far
+    let (mut digits, mut number) = (false, 0u64);
+    for &c in input.as_bytes().iter().chain(b"\n") {
+        match c {
+            b'0'..=b'9' => {
+                number = number * 10 + u64::from(c - b'0');
+                if number > u64::from(u32::MAX) {
+                    return Ok(None);
+                }
+                digits = true;
+            }
+            b' ' | b'\t' | b'\r' | b'\n' => {
+                if digits {
+                    if numbers == 2 {
+                        bail!("more than two numbers on a line");
+                    }
+                    line[numbers] = number as u32;
+                    numbers += 1;
+                    (digits, number) = (false, 0);
+                }
+                if c == b'\n' {
+                    match numbers {
+                        0 => {}
+                        2 => {
+                            left.push(line[0]);
+                            right.push(line[1]);
+                        }
+                        _ => bail!("only one number on a line"),
+                    }
+                    numbers = 0;
+                }
+            }
+            b'-' | b'+' => return Ok(None),
+            _ => bail!("invalid character {:?}", c as char),
+        }
+    }
+    Ok(Some((left, right)))
+}
+
+/// Lists of numbers below this are counted instead of sorted,
+/// unless they're so short that going through every number up to their largest one takes longer.
+const COUNTED: usize = 1 << 20;
+/// Lists at least this long are radix sorted, which isn't worth its buckets for shorter ones.
+const RADIX_SORTED: usize = 1 << 16;
+
+/// The total distance and the similarity score of both lists,
+/// going through them in order once, whether counted or sorted.
+fn scores(mut left: Vec<u32>, mut right: Vec<u32>) -> (u64, i64) {
+    let max = left.iter().chain(&right).copied().max().unwrap_or(0) as usize;
+    if max < COUNTED && max / 8 < left.len() {
+        return counted_scores(&left, &right, max);
+    }
+
+    if left.len() >= RADIX_SORTED {
+        radix_sort(&mut left);
+        radix_sort(&mut right);
+    } else {
+        left.sort_unstable();
+        right.sort_unstable();
+    }
+    let (mut distance, mut similarity) = (0, 0);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() {
+        let n = left[i];
+        let from = i;
+        while i < left.len() && left[i] == n {
+            distance += u64::from(n.abs_diff(right[i]));
+            i += 1;
+        }
+        while j < right.len() && right[j] < n {
+            j += 1;
+        }
+        let same = right[j..].iter().take_while(|&&m| m == n).count();
+        j += same;
+        similarity += i64::from(n) * (i - from) as i64 * same as i64;
+    }
+    (distance, similarity)
+}
+
+fn counted_scores(left: &[u32], right: &[u32], max: usize) -> (u64, i64) {
+    let mut counts = vec![[0u64; 2]; max + 1];
+    for (&a, &b) in left.iter().zip(right) {
+        counts[a as usize][0] += 1;
+        counts[b as usize][1] += 1;
+    }
+
+    // the `k`th smallest numbers of both lists are paired up,
+    // so every number on the left takes the smallest one left unpaired on the right
+    let (mut distance, mut similarity) = (0, 0);
+    let (mut m, mut unpaired) = (0, counts[0][1]);
+    for (n, &[on_left, on_right]) in counts.iter().enumerate() {
+        similarity += n as i64 * (on_left * on_right) as i64;
+        let mut left_over = on_left;
+        while left_over > 0 {
+            while unpaired == 0 {
+                m += 1;
+                unpaired = counts[m][1];
+            }
+            let paired = left_over.min(unpaired);
+            distance += paired * n.abs_diff(m) as u64;
+            left_over -= paired;
+            unpaired -= paired;
+        }
+    }
+    (distance, similarity)
+}
+
+/// Sorts 16 bits at a time, starting from the lowest ones.
+fn radix_sort(values: &mut Vec<u32>) {
+    let mut sorted = vec![0; values.len()];
+    for shift in [0, 16] {
+        let digit = |n: u32| (n >> shift) as usize & 0xffff;
+        let mut starts = vec![0; 1 << 16];
+        for &n in values.iter() {
+            starts[digit(n)] += 1;
+        }
+        let mut start = 0;
+        for count in &mut starts {
+            (*count, start) = (start, start + *count);
+        }
+        for &n in values.iter() {
+            sorted[starts[digit(n)]] = n;
+            starts[digit(n)] += 1;
+        }
+        std::mem::swap(values, &mut sorted);
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    match lists(input)? {
+        Some((left, right)) => Ok(scores(left, right).0),
+        None => part1::original(input),
+    }
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    match lists(input)? {
+        Some((left, right)) => Ok(scores(left, right).1),
+        None => part2::original(input),
+    }
+}
+
+/// The first solution, sorting both lists.
+pub mod part1 {
+    use aoc_lib::color_eyre::eyre::Result;
+
+    pub fn original(input: &str) -> Result<u64> {
+        let (mut a, mut b) = super::parse(input)?;
+        a.sort_unstable();
+        b.sort_unstable();
+        Ok(a.into_iter().zip(b).map(|(a, b)| a.abs_diff(b)).sum())
+    }
+}
+
+/// The first solution, counting the right list in a map.
+pub mod part2 {
+    use std::collections::HashMap;
+
+    use aoc_lib::color_eyre::eyre::Result;
+
+    pub fn original(input: &str) -> Result<i64> {
+        let (a, b) = super::parse(input)?;
+        let b = b.into_iter().fold(HashMap::new(), |mut acc, curr| {
+            acc.entry(curr).and_modify(|cnt| *cnt += 1).or_insert(1);
+            acc
+        });
+        Ok(a.into_iter()
+            .map(|x| b.get(&x).copied().unwrap_or(0) * x)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 31);
+    }
+
+    fn random_lists(len: usize, numbers: std::ops::Range<i64>, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        (0..len)
+            .map(|_| {
+                let (a, b) = (rng.range(numbers.clone()), rng.range(numbers.clone()));
+                format!("{a}   {b}\n")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let inputs = [
+            EX_INPUT.to_string(),
+            // counted, with lots of duplicates
+            random_lists(1000, 0..50, 0),
+            random_lists(20_000, 0..100_000, 1),
+            // sorted
+            random_lists(1000, 0..100_000, 2),
+            random_lists(1000, (1 << 32) - 10..1 << 32, 3),
+            // radix sorted
+            random_lists(RADIX_SORTED, 0..1 << 32, 4),
+            random_lists(RADIX_SORTED, (1 << 32) - 1000..1 << 32, 5),
+            // left to the first solutions
+            random_lists(1000, -50..50, 6),
+            random_lists(1000, 0..1 << 40, 7),
+        ];
+        for input in inputs {
+            assert_eq!(part1(&input).unwrap(), part1::original(&input).unwrap());
+            assert_eq!(part2(&input).unwrap(), part2::original(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in ["1   2\n3\n", "1   2   3\n", "1   x\n"] {
+            assert!(part1(input).is_err(), "{input:?}");
+            assert!(part2(input).is_err(), "{input:?}");
+        }
+    }
+}
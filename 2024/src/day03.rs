@@ -0,0 +1,88 @@
+use aoc_lib::color_eyre::eyre::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+/// The arguments of a `mul` and what's left after it, `s` starting right after `mul(`.
+fn mul_args(s: &str) -> Option<(u64, u64, &str)> {
+    let comma = s.find(',').filter(|&comma| comma <= 3)?;
+    let a = s[..comma].parse().ok()?;
+    let s = &s[comma + 1..];
+    let end = s.find(')').filter(|&end| end <= 3)?;
+    let b = s[..end].parse().ok()?;
+    Some((a, b, &s[end + 1..]))
+}
+
+/// Every valid instruction in the corrupted memory, skipping whatever's around them.
+pub fn parse(input: &str) -> Vec<Instruction> {
+    let mut s = input;
+    let mut res = Vec::new();
+
+    while let Some((idx, pattern)) = ["mul(", "do()", "don't()"]
+        .into_iter()
+        .filter_map(|pattern| Some((s.find(pattern)?, pattern)))
+        .min()
+    {
+        s = &s[idx + pattern.len()..];
+        match pattern {
+            "do()" => res.push(Instruction::Do),
+            "don't()" => res.push(Instruction::Dont),
+            _ => {
+                if let Some((a, b, rest)) = mul_args(s) {
+                    res.push(Instruction::Mul(a, b));
+                    s = rest;
+                }
+            }
+        }
+    }
+    res
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    Ok(parse(input)
+        .into_iter()
+        .map(|ins| match ins {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    let mut enabled = true;
+    let mut res = 0;
+    for ins in parse(input) {
+        match ins {
+            Instruction::Mul(a, b) if enabled => res += a * b,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT_1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+    static EX_INPUT_2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT_1).unwrap(), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT_2).unwrap(), 48);
+    }
+}
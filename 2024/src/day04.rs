@@ -0,0 +1,89 @@
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::{Grid, Point, PointExt},
+};
+
+fn is_xmas(grid: &Grid<'_>, point: Point, direction: Point) -> bool {
+    b"MAS"
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (point.add(&direction.map(|a| a * (i as i64 + 1))), c))
+        .all(|(pos, c)| grid.get_pos(pos).is_some_and(|v| v == c))
+}
+
+fn find_xmas(grid: &Grid<'_>, point: Point) -> usize {
+    grid.adjacent_pos(point)
+        .map(|(x, y)| (x - point.0, y - point.1))
+        .filter(|&dir| is_xmas(grid, point, dir))
+        .count()
+}
+
+pub fn parse(input: &str) -> Result<Grid<'_>> {
+    Grid::for_str(input.trim()).ok_or_eyre("invalid input")
+}
+
+/// Every position of `c` in the grid.
+fn find_all(grid: Grid<'_>, c: u8) -> impl Iterator<Item = Point> {
+    (0..grid.height())
+        .flat_map(move |y| (0..grid.width()).map(move |x| (x, y)))
+        .filter(move |&pos| grid[pos] == c)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let grid = parse(input)?;
+
+    Ok(find_all(grid, b'X').map(|pos| find_xmas(&grid, pos)).sum())
+}
+
+fn is_x_mas(grid: &Grid<'_>, point: Point) -> bool {
+    [-1, 1]
+        .into_iter()
+        .flat_map(|dx| [(dx, -1), (dx, 1)])
+        .filter(|&d| {
+            let pos_a = point.add(&d);
+            let dd = d.map(|a| -a);
+            let pos_b = point.add(&dd);
+            matches!(
+                (grid.get_pos(pos_a).copied(), grid.get_pos(pos_b).copied()),
+                (Some(b'M'), Some(b'S'))
+            )
+        })
+        .count()
+        == 2
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let grid = parse(input)?;
+
+    Ok(find_all(grid, b'A')
+        .filter(|&pos| is_x_mas(&grid, pos))
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 9);
+    }
+}
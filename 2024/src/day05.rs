@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+
+use aoc_common::parse::{pair, separated, split_sections};
+use aoc_lib::{
+    color_eyre::eyre::{Result, bail},
+    to_lines,
+};
+
+/// For every page, the pages that have to come after it.
+pub type Rules = [Vec<u8>; 100];
+
+/// The index of page `n` in [`Rules`], if it has one.
+fn page(n: u8) -> Result<usize> {
+    if n >= 100 {
+        bail!("page {n} out of range");
+    }
+    Ok(n.into())
+}
+
+pub fn parse(input: &str) -> Result<(Rules, Vec<Vec<u8>>)> {
+    let [rules, updates] = split_sections(input)?;
+    let mut res = [const { Vec::new() }; 100];
+
+    for line in to_lines(rules) {
+        let (idx, val) = pair::<u8>(line, "|")?;
+        res[page(idx)?].push(val);
+        page(val)?;
+    }
+
+    let updates = to_lines(updates)
+        .map(|line| {
+            let update = separated(line, ",")?;
+            for &val in &update {
+                page(val)?;
+            }
+            Ok(update)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((res, updates))
+}
+
+fn is_correctly_ordered(nums: &[u8], checks: &Rules) -> bool {
+    let mut iter = nums.iter().peekable();
+    let mut iter = std::iter::from_fn(|| {
+        let a = iter.next()?;
+        let b = iter.peek()?;
+        Some((a, *b))
+    });
+    iter.all(|(a, b)| !checks[*b as usize].contains(a))
+}
+
+pub fn part1(input: &str) -> Result<u32> {
+    let (checks, lines) = parse(input)?;
+
+    Ok(lines
+        .into_iter()
+        .filter(|nums| is_correctly_ordered(nums, &checks))
+        .map(|nums| nums[nums.len() / 2] as u32)
+        .sum())
+}
+
+fn fix_ordering(mut nums: Vec<u8>, checks: &Rules) -> Vec<u8> {
+    nums.sort_unstable_by(|a, b| {
+        if a == b {
+            Ordering::Equal
+        } else if checks[*a as usize].contains(b) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    });
+    nums
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    let (checks, lines) = parse(input)?;
+
+    Ok(lines
+        .into_iter()
+        .filter(|nums| !is_correctly_ordered(nums, &checks))
+        .map(|nums| fix_ordering(nums, &checks))
+        .map(|nums| nums[nums.len() / 2] as u32)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 123);
+    }
+
+    #[test]
+    fn invalid() {
+        for input in ["150|3\n\n3,150\n", "3|150\n\n3\n", "3|4\n\n3,150,4\n"] {
+            assert!(part1(input).is_err(), "{input:?}");
+        }
+    }
+}
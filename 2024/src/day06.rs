@@ -0,0 +1,182 @@
+use aoc_common::{
+    debugger::{self, Simulation, step_requested},
+    dir::Dir,
+    grid_map::GridSet,
+};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::{Grid, Point},
+};
+
+/// The guard walking their patrol, taking a step or turning right every time they're moved.
+#[derive(Debug, Clone)]
+pub struct Patrol<'a> {
+    grid: Grid<'a>,
+    pos: Point,
+    dir: Dir,
+    visited: GridSet,
+    left: bool,
+}
+
+impl<'a> Patrol<'a> {
+    pub fn new(grid: Grid<'a>, pos: Point) -> Self {
+        let mut visited = GridSet::for_grid(&grid);
+        visited.insert(pos);
+        Patrol {
+            grid,
+            pos,
+            dir: Dir::Up,
+            visited,
+            left: false,
+        }
+    }
+}
+
+impl Simulation for Patrol<'_> {
+    fn step(&mut self) -> bool {
+        if self.left {
+            return false;
+        }
+        let next_pos = self.dir.step(self.pos);
+        if !self.grid.is_valid_pos(next_pos) {
+            self.left = true;
+        } else if self.grid[next_pos] == b'#' {
+            self.dir = self.dir.turn_right();
+        } else {
+            self.pos = next_pos;
+            self.visited.insert(next_pos);
+        }
+        true
+    }
+
+    fn render(&self) -> String {
+        let mut res = String::new();
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                res.push(match (x, y) {
+                    pos if pos == self.pos && !self.left => self.dir.arrow() as char,
+                    pos if self.grid[pos] == b'#' => '#',
+                    pos if self.visited.contains(pos) => 'X',
+                    _ => '.',
+                });
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("visited", self.visited.len().to_string()),
+            ("guard", format!("{:?}", self.pos)),
+        ]
+    }
+
+    fn focus(&self) -> Option<Point> {
+        Some(self.pos)
+    }
+}
+
+fn get_visited_cells(grid: &Grid<'_>, starting_pos: Point) -> GridSet {
+    let mut patrol = Patrol::new(*grid, starting_pos);
+    while patrol.step() {}
+    patrol.visited
+}
+
+/// The lab, and where the guard starts in it.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Point)> {
+    let input = input.trim();
+    let grid = Grid::for_str(input).ok_or_eyre("invalid format")?;
+    let pos = input
+        .find('^')
+        .and_then(|idx| grid.idx_to_pos(idx))
+        .ok_or_eyre("invalid input")?;
+    Ok((grid, pos))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (grid, pos) = parse(input)?;
+
+    if step_requested() {
+        debugger::run(Patrol::new(grid, pos))?;
+    }
+
+    Ok(get_visited_cells(&grid, pos).len())
+}
+
+fn loops_for_input(
+    grid: &Grid<'_>,
+    starting_pos: Point,
+    check_point: impl Fn(Point) -> bool,
+) -> bool {
+    let mut visited = GridSet::<(Point, Dir)>::for_grid(grid);
+
+    let mut direction = Dir::Up;
+    let mut last_direction = direction;
+    let mut pos = starting_pos;
+
+    loop {
+        if last_direction != direction {
+            if !visited.insert((pos, direction)) {
+                return true;
+            }
+            last_direction = direction;
+        }
+
+        loop {
+            let next_pos = direction.step(pos);
+            if !grid.is_valid_pos(next_pos) {
+                return false;
+            }
+            if check_point(next_pos) {
+                pos = next_pos;
+                break;
+            } else {
+                direction = direction.turn_right();
+            }
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (grid, starting_pos) = parse(input)?;
+
+    let mut visited = get_visited_cells(&grid, starting_pos);
+    visited.remove(starting_pos);
+
+    Ok(visited
+        .iter()
+        .filter(|&replaced_pos| {
+            let check_point = |pos| grid[pos] != b'#' && pos != replaced_pos;
+            loops_for_input(&grid, starting_pos, check_point)
+        })
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 6);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::checked::{add, mul, pow};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    to_lines,
+};
+
+fn is_correct(total: u64, elems: &[u64]) -> bool {
+    fn try_op(elems: &[u64], idx: usize, total: u64, target: u64) -> bool {
+        let if_was_add = add(elems[idx], total);
+        let if_was_mul = mul(elems[idx], total);
+        if idx == elems.len() - 1 {
+            if_was_add == target || if_was_mul == target
+        } else {
+            try_op(elems, idx + 1, if_was_add, target) || try_op(elems, idx + 1, if_was_mul, target)
+        }
+    }
+    try_op(elems, 0, 0, total)
+}
+
+/// Every equation's test value along with its numbers.
+pub fn parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>> {
+    to_lines(input)
+        .map(|line| {
+            let (total, elems) = line.split_once(": ").ok_or_eyre("invalid format")?;
+            let elems = elems
+                .split_ascii_whitespace()
+                .map(|elem| Ok(elem.parse()?))
+                .collect::<Result<_>>()?;
+            Ok((total.parse()?, elems))
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<u128> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter(|(total, elems)| is_correct(*total, elems))
+        .map(|(total, _)| u128::from(total))
+        .sum())
+}
+
+fn is_correct_with_concat(total: u64, elems: &[u64]) -> bool {
+    fn try_op(elems: &[u64], idx: usize, total: u64, target: u64) -> bool {
+        let if_was_add = add(elems[idx], total);
+        let if_was_mul = mul(elems[idx], total);
+        let if_was_concat = {
+            let digits = elems[idx].ilog10() + 1;
+            add(mul(total, pow(10u64, digits)), elems[idx])
+        };
+        if idx == elems.len() - 1 {
+            if_was_add == target || if_was_mul == target || if_was_concat == target
+        } else {
+            try_op(elems, idx + 1, if_was_add, target)
+                || try_op(elems, idx + 1, if_was_mul, target)
+                || try_op(elems, idx + 1, if_was_concat, target)
+        }
+    }
+    try_op(elems, 0, 0, total)
+}
+
+pub fn part2(input: &str) -> Result<u128> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter(|(total, elems)| is_correct_with_concat(*total, elems))
+        .map(|(total, _)| u128::from(total))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 11387);
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_common::grid_map::GridSet;
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::{Grid, Point},
+};
+
+/// The map, and every trailhead on it.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Vec<Point>)> {
+    let input = input.trim();
+    let grid = Grid::for_str(input).ok_or_eyre("invalid format")?;
+    let trailheads = input
+        .bytes()
+        .enumerate()
+        .filter(|&(_, c)| c == b'0')
+        .filter_map(|(idx, _)| grid.idx_to_pos(idx))
+        .collect();
+    Ok((grid, trailheads))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (grid, trailheads) = parse(input)?;
+
+    /// How many summits can be reached from `start`.
+    fn score(grid: &Grid<'_>, start: Point) -> usize {
+        let mut visited = GridSet::for_grid(grid);
+        visited.insert(start);
+        let mut stack = vec![start];
+        let mut res = 0;
+        while let Some(pos) = stack.pop() {
+            if grid[pos] == b'9' {
+                res += 1;
+                continue;
+            }
+            let val = grid[pos];
+            for next_pos in grid.orthogonal_pos(pos) {
+                if grid[next_pos].wrapping_sub(val) == 1 && !visited.contains(next_pos) {
+                    visited.insert(next_pos);
+                    stack.push(next_pos);
+                }
+            }
+        }
+        res
+    }
+
+    Ok(trailheads.into_iter().map(|pos| score(&grid, pos)).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (grid, trailheads) = parse(input)?;
+
+    /// How many distinct trails lead from `start` to a summit.
+    fn rating(grid: &Grid<'_>, start: Point) -> usize {
+        let mut stack = vec![start];
+        let mut res = 0;
+        while let Some(pos) = stack.pop() {
+            if grid[pos] == b'9' {
+                res += 1;
+                continue;
+            }
+            let val = grid[pos];
+            stack.extend(
+                grid.orthogonal_pos(pos)
+                    .filter(|next_pos| grid[*next_pos].wrapping_sub(val) == 1),
+            );
+        }
+        res
+    }
+
+    Ok(trailheads.into_iter().map(|pos| rating(&grid, pos)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 81);
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use aoc_common::checked::{self, add, mul};
+use aoc_lib::color_eyre::eyre::Result;
+
+/// The numbers engraved on the stones.
+pub fn parse(input: &str) -> Result<Vec<usize>> {
+    input
+        .split_ascii_whitespace()
+        .map(|stone| Ok(stone.parse()?))
+        .collect()
+}
+
+/// How many stones there are after `blinks` blinks.
+/// The count grows exponentially, so it's kept wider than the stones themselves.
+fn solve(stones: &[usize], blinks: usize) -> u128 {
+    let mut groups = HashMap::new();
+    let mut next_groups = HashMap::new();
+
+    fn insert_group(groups: &mut HashMap<usize, u128>, stone: usize, cnt: u128) {
+        groups
+            .entry(stone)
+            .and_modify(|c| *c = add(*c, cnt))
+            .or_insert(cnt);
+    }
+
+    for &stone in stones {
+        insert_group(&mut groups, stone, 1);
+    }
+
+    for _ in 0..blinks {
+        next_groups.clear();
+        for (&stone, &cnt) in &groups {
+            if stone == 0 {
+                insert_group(&mut next_groups, 1, cnt);
+            } else {
+                let digits = stone.ilog10() + 1;
+                if digits & 1 == 0 {
+                    let d = 10usize.pow(digits / 2);
+                    let new_stone_a = stone / d;
+                    let new_stone_b = stone - new_stone_a * d;
+                    insert_group(&mut next_groups, new_stone_a, cnt);
+                    insert_group(&mut next_groups, new_stone_b, cnt);
+                } else {
+                    insert_group(&mut next_groups, mul(stone, 2024), cnt);
+                }
+            }
+        }
+        std::mem::swap(&mut groups, &mut next_groups);
+    }
+
+    checked::sum(groups.values().copied())
+}
+
+pub fn part1(input: &str) -> Result<u128> {
+    Ok(solve(&parse(input)?, 25))
+}
+
+pub fn part2(input: &str) -> Result<u128> {
+    Ok(solve(&parse(input)?, 75))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = "125 17";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 65601038650482);
+    }
+
+    #[test]
+    fn count_beyond_64_bits() {
+        assert!(solve(&parse(EX_INPUT).unwrap(), 150) > u64::MAX.into());
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_common::parse::{n_ints, sections};
+use aoc_lib::{color_eyre::eyre::Result, grid::PointExt};
+
+/// Every claw machine's button A, button B and prize, as `[ax, ay, bx, by, px, py]`.
+pub fn parse(input: &str) -> Result<Vec<[i64; 6]>> {
+    sections(input).map(n_ints).collect()
+}
+
+fn find_optimal(machine: [i64; 6], prize_offset: i64) -> Option<usize> {
+    let [ax, ay, bx, by, px, py] = machine;
+    let (a, b) = ((ax, ay), (bx, by));
+    let prize = (px, py).map(|n| n + prize_offset);
+
+    // px = a * ax + b * bx
+    // py = a * ay + b * by
+    //
+    // b * bx = px - a * ax
+    // b = (px - a * ax) / bx
+    //
+    // py = a * ay + (px - a * ax) / bx * by
+    // py * bx = a * ay * bx + (px - a * ax) * by
+    // py * bx = a * ay * bx + px * by - a * ax * by
+    // py * bx - px * by = a * ay * bx - a * ax * by
+    // py * bx - px * by = a(ay * bx - ax * by)
+    // (py * bx - px * by) / (ay * bx - ax * by) = a
+    let numerator = (prize.1 * b.0 - prize.0 * b.1) as f64;
+    let denominator = (a.1 * b.0 - a.0 * b.1) as f64;
+    let maybe_a_presses = numerator / denominator;
+    if maybe_a_presses.floor() != maybe_a_presses {
+        return None;
+    }
+
+    let a_presses = maybe_a_presses;
+    let b_presses = (prize.0 as f64 - a_presses * a.0 as f64) / b.0 as f64;
+    if b_presses.floor() != b_presses {
+        return None;
+    }
+
+    let a_presses = a_presses as i64;
+    let b_presses = b_presses as i64;
+    let cost = a_presses * 3 + b_presses;
+    Some(cost as usize)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter_map(|machine| find_optimal(machine, 0))
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter_map(|machine| find_optimal(machine, 10000000000000))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 480);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 875318608908);
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use aoc_common::parse::n_ints;
+use aoc_lib::{
+    color_eyre::eyre::Result,
+    grid::{Point, PointExt},
+    to_lines,
+};
+
+#[cfg(test)]
+const WIDTH: i64 = 11;
+#[cfg(test)]
+const HEIGHT: i64 = 7;
+#[cfg(not(test))]
+const WIDTH: i64 = 101;
+#[cfg(not(test))]
+const HEIGHT: i64 = 103;
+
+pub fn parse(input: &str) -> Result<Vec<(Point, Point)>> {
+    to_lines(input)
+        .map(|line| {
+            let [x, y, dx, dy] = n_ints(line)?;
+            Ok(((x, y), (dx, dy)))
+        })
+        .collect()
+}
+
+fn pos_after_steps((pos, vel): (Point, Point), steps: i64) -> Point {
+    let (x, y) = vel.map(|c| c * steps).add(&pos);
+    (x.rem_euclid(WIDTH), y.rem_euclid(HEIGHT))
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    let (a, b, c, d) = parse(input)?
+        .into_iter()
+        .map(|robot| pos_after_steps(robot, 100))
+        .fold((0, 0, 0, 0), |mut acc, pos| {
+            const HALF_WIDTH: i64 = WIDTH / 2;
+            const HALF_HEIGHT: i64 = HEIGHT / 2;
+            match pos.0 {
+                HALF_WIDTH => (),
+                ..HALF_WIDTH => match pos.1 {
+                    HALF_HEIGHT => (),
+                    ..HALF_HEIGHT => acc.0 += 1,
+                    HALF_HEIGHT.. => acc.2 += 1,
+                },
+                HALF_WIDTH.. => match pos.1 {
+                    HALF_HEIGHT => (),
+                    ..HALF_HEIGHT => acc.1 += 1,
+                    HALF_HEIGHT.. => acc.3 += 1,
+                },
+            }
+            acc
+        });
+    Ok(a * b * c * d)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let robots = parse(input)?;
+
+    Ok((1..)
+        .find(|&steps| {
+            let positions: HashSet<_> = robots
+                .iter()
+                .map(|&state| pos_after_steps(state, steps))
+                .collect();
+
+            positions.len() == robots.len()
+        })
+        .unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 12);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 1);
+    }
+}
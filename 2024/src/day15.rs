@@ -0,0 +1,282 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{
+    debugger::{self, Simulation, step_requested},
+    dir::Dir,
+    grid_buf::GridBuf,
+    parse::split_sections,
+};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::Point,
+    to_lines,
+};
+
+fn move_box_narrow(grid: &mut GridBuf<u8>, pos: Point, dir: Dir) -> bool {
+    let mut end = pos;
+    while grid[end] == b'O' {
+        end = dir.step(end);
+    }
+    if grid[end] == b'#' {
+        return false;
+    }
+    // shifting the whole row of boxes by one is the same as moving the first one past the last
+    if end != pos {
+        grid[end] = b'O';
+        grid[pos] = b'.';
+    }
+    true
+}
+
+/// Position of the left half of the wide box covering `pos`, if there is one.
+fn wide_box_at(grid: &GridBuf<u8>, pos: Point) -> Option<Point> {
+    match grid[pos] {
+        b'[' => Some(pos),
+        b']' => Some((pos.0 - 1, pos.1)),
+        _ => None,
+    }
+}
+
+/// The left halves of every wide box pushed by moving into `pos`, closest first,
+/// or `None` if any of them would be pushed into a wall.
+fn boxes_pushed(grid: &GridBuf<u8>, pos: Point, dir: Dir) -> Option<Vec<Point>> {
+    let mut res = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([pos]);
+    while let Some(pos) = queue.pop_front() {
+        if grid[pos] == b'#' {
+            return None;
+        }
+        let Some(left) = wide_box_at(grid, pos) else {
+            continue;
+        };
+        if !seen.insert(left) {
+            continue;
+        }
+        res.push(left);
+        let right = (left.0 + 1, left.1);
+        match dir {
+            Dir::Left => queue.push_back(dir.step(left)),
+            Dir::Right => queue.push_back(dir.step(right)),
+            Dir::Up | Dir::Down => queue.extend([dir.step(left), dir.step(right)]),
+        }
+    }
+    Some(res)
+}
+
+fn maybe_move_box_wide(grid: &mut GridBuf<u8>, pos: Point, dir: Dir) -> bool {
+    let Some(boxes) = boxes_pushed(grid, pos, dir) else {
+        return false;
+    };
+    // the farthest boxes go first, making room for the ones pushing them
+    for &left in boxes.iter().rev() {
+        let right = (left.0 + 1, left.1);
+        grid[left] = b'.';
+        grid[right] = b'.';
+        grid[dir.step(left)] = b'[';
+        grid[dir.step(right)] = b']';
+    }
+    true
+}
+
+fn gps_sum(grid: &GridBuf<u8>, box_cell: u8) -> i64 {
+    grid.iter()
+        .filter(|&(_, &c)| c == box_cell)
+        .map(|((x, y), _)| x + 100 * y)
+        .sum()
+}
+
+/// The warehouse, with the robot making one of its moves on every step.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    grid: GridBuf<u8>,
+    robot: Point,
+    moves: Vec<Dir>,
+    next_move: usize,
+    /// Whether the boxes are twice as wide, taking up `[]` instead of `O`.
+    wide: bool,
+}
+
+/// The warehouse as it's drawn, and the moves the robot will make.
+pub fn parse(input: &str) -> Result<(GridBuf<u8>, Vec<Dir>)> {
+    let [grid, moves] = split_sections(input)?;
+    let grid = GridBuf::for_str(grid).ok_or_eyre("invalid format")?;
+    let moves = to_lines(moves)
+        .flat_map(str::bytes)
+        .map(|c| Dir::from_byte(c).ok_or_eyre("invalid move"))
+        .collect::<Result<_>>()?;
+    Ok((grid, moves))
+}
+
+impl Warehouse {
+    pub fn new(mut grid: GridBuf<u8>, moves: Vec<Dir>, wide: bool) -> Result<Self> {
+        if wide {
+            grid = GridBuf::from_rows(grid.iter_rows().map(|row| {
+                row.iter().flat_map(|&c| match c {
+                    b'O' => *b"[]",
+                    b'@' => *b"@.",
+                    c => [c, c],
+                })
+            }))
+            .ok_or_eyre("invalid format")?;
+        }
+
+        let robot = grid.find(&b'@').ok_or_eyre("no robot")?;
+        grid[robot] = b'.';
+
+        Ok(Warehouse {
+            grid,
+            robot,
+            moves,
+            next_move: 0,
+            wide,
+        })
+    }
+
+    fn gps_sum(&self) -> i64 {
+        gps_sum(&self.grid, if self.wide { b'[' } else { b'O' })
+    }
+}
+
+impl Simulation for Warehouse {
+    fn step(&mut self) -> bool {
+        let Some(&dir) = self.moves.get(self.next_move) else {
+            return false;
+        };
+        self.next_move += 1;
+        let next_pos = dir.step(self.robot);
+        let moved = if self.wide {
+            maybe_move_box_wide(&mut self.grid, next_pos, dir)
+        } else {
+            move_box_narrow(&mut self.grid, next_pos, dir)
+        };
+        if moved {
+            self.robot = next_pos;
+        }
+        true
+    }
+
+    fn render(&self) -> String {
+        let mut grid = self.grid.clone();
+        grid[self.robot] = b'@';
+        grid.render(|&c| c as char)
+    }
+
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        let next = match self.moves.get(self.next_move) {
+            Some(dir) => (dir.arrow() as char).to_string(),
+            None => "-".to_string(),
+        };
+        vec![
+            ("move", format!("{}/{}", self.next_move, self.moves.len())),
+            ("next", next),
+            ("gps", self.gps_sum().to_string()),
+        ]
+    }
+
+    fn focus(&self) -> Option<Point> {
+        Some(self.robot)
+    }
+}
+
+fn solve(input: &str, wide: bool) -> Result<i64> {
+    let (grid, moves) = parse(input)?;
+    let mut warehouse = Warehouse::new(grid, moves, wide)?;
+    if step_requested() {
+        debugger::run(warehouse.clone())?;
+    }
+    while warehouse.step() {}
+    Ok(warehouse.gps_sum())
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    solve(input, false)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    solve(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+"#;
+
+    #[allow(dead_code)]
+    static SMALL_1: &str = r#"
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+"#;
+
+    #[allow(dead_code)]
+    static SMALL_2: &str = r#"
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 10092);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 9021);
+    }
+
+    /// A single column of `n` boxes with the robot under them, pushing them all up once.
+    fn column(n: usize) -> String {
+        let mut res = "###\n#.#\n".to_string();
+        res += &"#O#\n".repeat(n);
+        res += "#@#\n###\n\n^\n";
+        res
+    }
+
+    #[test]
+    fn long_column() {
+        let n = 100_000;
+        let input = column(n as usize);
+        // the boxes end up on rows 1 to n, on the second column
+        let rows = 100 * n * (n + 1) / 2;
+        assert_eq!(part1(&input).unwrap(), n + rows);
+        assert_eq!(part2(&input).unwrap(), 2 * n + rows);
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    dir::Dir,
+    search::{Paths, dijkstra},
+};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::{Grid, Point},
+};
+
+/// The maze, and where it starts and ends.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Point, Point)> {
+    let input = input.trim();
+    let grid = Grid::for_str(input).ok_or_eyre("invalid format")?;
+
+    let start = input
+        .as_bytes()
+        .iter()
+        .position(|&x| x == b'S')
+        .and_then(|i| grid.idx_to_pos(i))
+        .ok_or_eyre("invalid format")?;
+    let end = input
+        .as_bytes()
+        .iter()
+        .position(|&x| x == b'E')
+        .and_then(|i| grid.idx_to_pos(i))
+        .ok_or_eyre("invalid format")?;
+
+    Ok((grid, start, end))
+}
+
+/// Cheapest ways from the start, facing east, to the end, facing any direction.
+fn search(input: &str) -> Result<Paths<(Point, Dir), i64>> {
+    let (grid, start, end) = parse(input)?;
+
+    let neighbors = |&(pos, dir): &(Point, Dir)| {
+        [
+            (dir, 1),
+            (dir.turn_left(), 1001),
+            (dir.turn_right(), 1001),
+            (dir.turn_around(), 2001),
+        ]
+        .into_iter()
+        .map(move |(dir, cost)| ((dir.step(pos), dir), cost))
+        .filter(|&((pos, _), _)| grid.get_pos(pos).is_some_and(|&c| c != b'#'))
+    };
+
+    Ok(dijkstra((start, Dir::Right), neighbors, |&(pos, _)| {
+        pos == end
+    }))
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    search(input)?.goal_cost().ok_or_eyre("no path to the end")
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let paths = search(input)?;
+    let tiles: HashSet<_> = paths
+        .nodes_on_paths(paths.goals())
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+    Ok(tiles.len())
+}
+
+/// Open tiles next to `curr`, the direction they're in and the cost of stepping on them facing `dir`,
+/// for the first solutions.
+fn neighbors(
+    grid: &Grid<'_>,
+    curr: Point,
+    dir: Point,
+) -> impl DoubleEndedIterator<Item = (Point, Point, i64)> {
+    [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .into_iter()
+        .map(move |next_dir: Point| (next_dir, (curr.0 + next_dir.0, curr.1 + next_dir.1)))
+        .filter(|&(_, pos)| grid.is_valid_pos(pos) && grid[pos] != b'#')
+        .map(move |(next_dir, next_pos)| {
+            let cost = 1i64 + if next_dir == dir { 0 } else { 1000 };
+            (next_pos, next_dir, cost)
+        })
+}
+
+/// The first solution, a depth-first search skipping tiles already reached more cheaply.
+pub mod part1 {
+    use std::collections::HashMap;
+
+    use aoc_lib::color_eyre::eyre::Result;
+
+    pub fn original(input: &str) -> Result<i64> {
+        let (grid, start, end) = super::parse(input)?;
+
+        let mut visited = HashMap::new();
+        let mut min_cost = i64::MAX;
+        let mut stack = vec![(start, (1, 0), 0)];
+        while let Some((curr, dir, points)) = stack.pop() {
+            // checked when the tile comes up, after everything pushed later has been explored
+            if visited.get(&curr).is_some_and(|&c| c < points) {
+                continue;
+            }
+            visited.insert(curr, points);
+            if curr == end {
+                min_cost = min_cost.min(points);
+                continue;
+            }
+
+            // pushed in reverse, so they're explored in order
+            for (neighbor, next_dir, cost) in super::neighbors(&grid, curr, dir).rev() {
+                stack.push((neighbor, next_dir, points + cost));
+            }
+        }
+
+        Ok(min_cost)
+    }
+}
+
+/// The first solution, the same search remembering where the cheapest paths came from.
+pub mod part2 {
+    use std::collections::{HashMap, HashSet};
+
+    use aoc_lib::{color_eyre::eyre::Result, grid::Point};
+
+    pub fn original(input: &str) -> Result<usize> {
+        let (grid, start, end) = super::parse(input)?;
+
+        let actual_min_cost = super::part1::original(input)?;
+
+        /// A tile being explored, and the cheapest way to the end found from it so far.
+        struct Frame {
+            curr: Point,
+            dir: Point,
+            points: i64,
+            neighbors: std::vec::IntoIter<(Point, Point, i64)>,
+            min_cost: i64,
+        }
+
+        let frame = |curr, dir, points| Frame {
+            curr,
+            dir,
+            points,
+            neighbors: super::neighbors(&grid, curr, dir)
+                .collect::<Vec<_>>()
+                .into_iter(),
+            min_cost: i64::MAX,
+        };
+
+        let mut visited = HashMap::new();
+        let mut parents = HashMap::<Point, Vec<Point>>::new();
+        let mut stack = vec![frame(start, (1, 0), 0)];
+
+        let min_cost = loop {
+            let top = stack.last_mut().unwrap();
+            let Some((neighbor, next_dir, cost)) = top.neighbors.next() else {
+                // every way on from this tile has been explored, let the one before it know
+                let done = stack.pop().unwrap();
+                let Some(prev) = stack.last_mut() else {
+                    break done.min_cost;
+                };
+                if done.min_cost == actual_min_cost {
+                    parents.entry(done.curr).or_default().push(prev.curr);
+                }
+                prev.min_cost = prev.min_cost.min(done.min_cost);
+                continue;
+            };
+
+            let total_cost = top.points + cost;
+            if visited.get(&neighbor).is_some_and(|&c| c < total_cost) {
+                continue;
+            }
+            if next_dir != top.dir {
+                visited.insert(neighbor, total_cost);
+            }
+
+            let next_cost = if neighbor == end {
+                total_cost
+            } else if actual_min_cost < total_cost {
+                i64::MAX
+            } else {
+                stack.push(frame(neighbor, next_dir, total_cost));
+                continue;
+            };
+            if next_cost == actual_min_cost {
+                parents.entry(neighbor).or_default().push(top.curr);
+            }
+            top.min_cost = top.min_cost.min(next_cost);
+        };
+
+        assert_eq!(min_cost, actual_min_cost);
+
+        let mut path = HashSet::new();
+        let mut queue = Vec::new();
+
+        queue.push(end);
+
+        while let Some(pos) = queue.pop() {
+            path.insert(pos);
+            queue.extend(
+                parents
+                    .get(&pos)
+                    .into_iter()
+                    .flatten()
+                    .filter(|pos| !path.contains(pos)),
+            );
+        }
+
+        Ok(path.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{maze, rng::Rng};
+
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+"#;
+
+    static BIG: &str = r#"
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 7036);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 45);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        for example in [EX_INPUT, BIG] {
+            assert_eq!(part1::original(example).unwrap(), part1(example).unwrap());
+            assert_eq!(part2::original(example).unwrap(), part2(example).unwrap());
+        }
+    }
+
+    #[test]
+    fn random_mazes() {
+        for seed in 0..10 {
+            // with only one way to get anywhere, the first solutions agree
+            let input = maze::branching(21, 21, 0.0, &mut Rng::new(seed));
+            assert_eq!(part1::original(&input).unwrap(), part1(&input).unwrap());
+            assert_eq!(part2::original(&input).unwrap(), part2(&input).unwrap());
+
+            // with loops they can end up more expensive, since they skip tiles reached
+            // more cheaply before, even when that was facing a worse way
+            let input = maze::branching(21, 21, 0.1, &mut Rng::new(seed));
+            assert!(part1::original(&input).unwrap() >= part1(&input).unwrap());
+        }
+    }
+
+    /// A maze of `rows` corridors `width` tiles long, snaking down from the start to the end.
+    fn corridor(width: usize, rows: usize) -> String {
+        let wall = "#".repeat(width + 2);
+        let mut res = wall.clone() + "\n";
+        for i in 0..rows {
+            let mut row = vec![b'.'; width];
+            if i == 0 {
+                row[0] = b'S';
+            }
+            if i == rows - 1 {
+                row[if i % 2 == 0 { width - 1 } else { 0 }] = b'E';
+            }
+            res += &format!("#{}#\n", String::from_utf8(row).unwrap());
+            if i < rows - 1 {
+                let mut wall = vec![b'#'; width];
+                wall[if i % 2 == 0 { width - 1 } else { 0 }] = b'.';
+                res += &format!("#{}#\n", String::from_utf8(wall).unwrap());
+            }
+        }
+        res + &wall + "\n"
+    }
+
+    #[test]
+    fn long_corridor() {
+        let input = corridor(300, 300);
+        assert_eq!(part1::original(&input).unwrap(), part1(&input).unwrap());
+        // every tile is on the only path
+        assert_eq!(part2(&input).unwrap(), 300 * 300 + 299);
+        // not `part2::original`, which walks back and forth along the corridors
+        // as long as it stays under the cheapest cost, taking quadratic time
+    }
+}
@@ -0,0 +1,381 @@
+use aoc_common::{
+    answer::Answer,
+    parse::{labeled, n_ints, separated, split_sections},
+};
+use aoc_lib::color_eyre::eyre::{OptionExt, Result, bail};
+
+fn combo_op(op: u8, regs: &[i64; 3]) -> i64 {
+    match op {
+        b'0'..=b'3' => (op - b'0').into(),
+        b'4'..=b'6' => regs[(op - b'4') as usize],
+        _ => unreachable!(),
+    }
+}
+
+fn literal_op(op: u8) -> i64 {
+    (op - b'0').into()
+}
+
+macro_rules! get_val {
+    ($program:expr => $idx:expr) => {{
+        if $program.len() > $idx {
+            $program[$idx]
+        } else {
+            break;
+        }
+    }};
+}
+
+/// Runs the program, returning everything it outputs.
+pub fn eval(mut regs: [i64; 3], program: &[u8]) -> Vec<i64> {
+    let mut out = Vec::new();
+    let mut pc = 0;
+
+    while pc < program.len() {
+        let ins = get_val!(program => pc);
+        match ins {
+            b'0' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[0] = numerator >> denominator;
+
+                pc += 2;
+            }
+            b'1' => {
+                let a = regs[1];
+                let b = literal_op(get_val!(program => pc + 1));
+                regs[1] = a ^ b;
+
+                pc += 2;
+            }
+            b'2' => {
+                let val = combo_op(get_val!(program => pc + 1), &regs);
+                regs[1] = val & 7;
+
+                pc += 2;
+            }
+            b'3' => {
+                if regs[0] == 0 {
+                    pc += 2;
+                } else {
+                    pc = literal_op(get_val!(program => pc + 1)) as usize;
+                }
+            }
+            b'4' => {
+                let a = regs[1];
+                let b = regs[2];
+                regs[1] = a ^ b;
+
+                pc += 2;
+            }
+            b'5' => {
+                let val = combo_op(get_val!(program => pc + 1), &regs);
+                let val = val & 7;
+                out.push(val);
+
+                pc += 2;
+            }
+            b'6' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[1] = numerator >> denominator;
+
+                pc += 2;
+            }
+            b'7' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[2] = numerator >> denominator;
+
+                pc += 2;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    out
+}
+
+/// Checks that the program is a single loop which shifts A by 3 bits
+/// and outputs one value on every iteration, computed only from A,
+/// which is what lets `find_ouroboros` find A three bits at a time.
+fn check_loop(program: &[u8]) -> Result<()> {
+    if !program.len().is_multiple_of(2) {
+        bail!("the program ends with an opcode without an operand");
+    }
+    let ins: Vec<_> = program.chunks(2).collect();
+    let count = |op| ins.iter().filter(|ins| ins[0] == op).count();
+
+    if count(b'3') != 1 || ins[ins.len() - 1] != b"30" {
+        bail!("the program isn't a single loop, it should only jump back to the start at its end");
+    }
+    if count(b'0') != 1 || !ins.contains(&&b"03"[..]) {
+        bail!("the program should shift A by 3 exactly once per iteration");
+    }
+    if count(b'5') != 1 {
+        bail!("the program should output exactly one value per iteration");
+    }
+
+    // B and C are only allowed to be read after being set in the same iteration
+    let mut set = [true, false, false];
+    for ins in ins {
+        let combo = (matches!(ins[0], b'0' | b'2' | b'5' | b'6' | b'7')
+            && (b'4'..=b'6').contains(&ins[1]))
+        .then(|| ins[1] - b'4');
+        let reads = match ins[0] {
+            b'1' => [Some(1), None],
+            b'4' => [Some(1), Some(2)],
+            _ => [combo, None],
+        };
+        if let Some(reg) = reads.into_iter().flatten().find(|&reg| !set[reg as usize]) {
+            bail!(
+                "the program reads {} before setting it, so the iterations depend on each other",
+                ["A", "B", "C"][reg as usize]
+            );
+        }
+        match ins[0] {
+            b'1' | b'2' | b'4' | b'6' => set[1] = true,
+            b'7' => set[2] = true,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The lowest value of register A making the program output itself, trying every one in turn.
+pub fn find_ouroboros(program: &[u8]) -> Result<i64> {
+    check_loop(program)?;
+
+    fn find_target(prev_a: i64, idx: usize, program: &[u8]) -> Option<i64> {
+        (0..8)
+            .filter_map(|maybe_a| {
+                let a = maybe_a | (prev_a << 3);
+
+                let mut regs = [a, 0, 0];
+                let mut pc = 0;
+                let mut out_val = 0;
+                loop {
+                    let ins = program[pc];
+                    match ins {
+                        b'0' => {
+                            let numerator = regs[0];
+                            let denominator = combo_op(program[pc + 1], &regs);
+                            regs[0] = numerator >> denominator;
+
+                            pc += 2;
+                        }
+                        b'1' => {
+                            let a = regs[1];
+                            let b = literal_op(program[pc + 1]);
+                            regs[1] = a ^ b;
+
+                            pc += 2;
+                        }
+                        b'2' => {
+                            let val = combo_op(program[pc + 1], &regs);
+                            regs[1] = val & 7;
+
+                            pc += 2;
+                        }
+                        b'3' => {
+                            break;
+                        }
+                        b'4' => {
+                            let a = regs[1];
+                            let b = regs[2];
+                            regs[1] = a ^ b;
+
+                            pc += 2;
+                        }
+                        b'5' => {
+                            let val = combo_op(program[pc + 1], &regs);
+                            out_val = (val & 7) as u8 + b'0';
+
+                            pc += 2;
+                        }
+                        b'6' => {
+                            let numerator = regs[0];
+                            let denominator = combo_op(program[pc + 1], &regs);
+                            regs[1] = numerator >> denominator;
+
+                            pc += 2;
+                        }
+                        b'7' => {
+                            let numerator = regs[0];
+                            let denominator = combo_op(program[pc + 1], &regs);
+                            regs[2] = numerator >> denominator;
+
+                            pc += 2;
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                if out_val == program[idx] {
+                    if idx == 0 {
+                        Some(a)
+                    } else {
+                        find_target(a, idx - 1, program)
+                    }
+                } else {
+                    None
+                }
+            })
+            .min()
+    }
+
+    #[cfg(not(test))]
+    let res = find_target(0, program.len() - 1, program);
+
+    #[cfg(test)]
+    let res = (0..i64::MAX).find(|&a| eval_ouroboros([a, 0, 0], program));
+
+    res.ok_or_eyre("no value of A makes the program output itself")
+}
+
+/// Whether the program outputs itself, stopping as soon as it outputs anything else.
+pub fn eval_ouroboros(mut regs: [i64; 3], program: &[u8]) -> bool {
+    let mut out = Vec::new();
+    let mut pc = 0;
+
+    while pc < program.len() {
+        let ins = get_val!(program => pc);
+        match ins {
+            b'0' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[0] = numerator >> denominator;
+
+                pc += 2;
+            }
+            b'1' => {
+                let a = regs[1];
+                let b = literal_op(get_val!(program => pc + 1));
+                regs[1] = a ^ b;
+
+                pc += 2;
+            }
+            b'2' => {
+                let val = combo_op(get_val!(program => pc + 1), &regs);
+                regs[1] = val & 7;
+
+                pc += 2;
+            }
+            b'3' => {
+                if regs[0] == 0 {
+                    pc += 2;
+                } else {
+                    pc = literal_op(get_val!(program => pc + 1)) as usize;
+                }
+            }
+            b'4' => {
+                let a = regs[1];
+                let b = regs[2];
+                regs[1] = a ^ b;
+
+                pc += 2;
+            }
+            b'5' => {
+                let val = combo_op(get_val!(program => pc + 1), &regs);
+                let val = (val & 7) as u8 + b'0';
+                out.push(val);
+
+                if out.len() > program.len() || val != program[out.len() - 1] {
+                    return false;
+                }
+
+                pc += 2;
+            }
+            b'6' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[1] = numerator >> denominator;
+
+                pc += 2;
+            }
+            b'7' => {
+                let numerator = regs[0];
+                let denominator = combo_op(get_val!(program => pc + 1), &regs);
+                regs[2] = numerator >> denominator;
+
+                pc += 2;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    out == program
+}
+
+/// Registers and the program, with the opcodes and operands kept as ASCII digits.
+pub fn parse(input: &str) -> Result<([i64; 3], Vec<u8>)> {
+    let [regs, program] = split_sections(input)?;
+    let regs = n_ints(regs)?;
+    let (_, program) = labeled(program)?;
+    let program = separated::<u8>(program, ",")?
+        .into_iter()
+        .map(|n| {
+            if n >= 8 {
+                bail!("invalid opcode/operand {n}");
+            }
+            Ok(n + b'0')
+        })
+        .collect::<Result<_>>()?;
+    Ok((regs, program))
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let (regs, program) = parse(input)?;
+    Ok(eval(regs, &program).into())
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let (_, program) = parse(input)?;
+    find_ouroboros(&program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+"#;
+
+    static EX_INPUT_B: &str = r#"
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0 
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT_B).unwrap(), 117440);
+    }
+
+    #[test]
+    fn part2_dependent_iterations() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,1,1,5,5,3,0";
+        let err = part2(input).unwrap_err().to_string();
+        assert!(err.contains("reads B before setting it"), "{err}");
+    }
+
+    #[test]
+    fn invalid() {
+        for program in ["210,0", "9,0"] {
+            let input =
+                format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}");
+            assert!(part1(&input).is_err(), "{program}");
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_common::{answer::Answer, parse::points, search::bfs, sparse_grid::SparseGrid};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::Point,
+};
+
+const WIDTH: i64 = 70;
+const HEIGHT: i64 = 70;
+
+/// Where the bytes fall, in the order they do.
+pub fn parse(input: &str) -> Result<Vec<Point>> {
+    points(input)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut memory = SparseGrid::bounded((0, 0), (WIDTH, HEIGHT));
+    for point in parse(input)?.into_iter().take(1024) {
+        // bytes falling outside the memory space can't block anything
+        if memory.is_valid_pos(point) {
+            memory.set(point, b'#');
+        }
+    }
+
+    bfs(
+        (0, 0),
+        |&pos| {
+            memory
+                .orthogonal_pos(pos)
+                .filter(|&pos| !memory.contains(pos))
+        },
+        |&pos| pos == (WIDTH, HEIGHT),
+    )
+    .goal_cost()
+    .ok_or_eyre("no path to the exit")
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut iter = parse(input)?.into_iter();
+
+    let mut memory = SparseGrid::bounded((0, 0), (WIDTH, HEIGHT));
+    for point in iter.by_ref().take(1024) {
+        if memory.is_valid_pos(point) {
+            memory.set(point, b'#');
+        }
+    }
+
+    for point in iter {
+        if !memory.is_valid_pos(point) || memory.set(point, b'#').is_some() {
+            continue;
+        }
+
+        let paths = bfs(
+            (0, 0),
+            |&pos| {
+                memory
+                    .orthogonal_pos(pos)
+                    .filter(|&pos| !memory.contains(pos))
+            },
+            |&pos| pos == (WIDTH, HEIGHT),
+        );
+
+        if paths.goal().is_none() {
+            return Ok(Answer::Point(point));
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = "EXAMPLE 1 HERE";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 0);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), "");
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        assert_eq!(part1("1,0\n-1,0\n100,100\n").unwrap(), 140);
+    }
+}
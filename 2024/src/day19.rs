@@ -0,0 +1,110 @@
+use aoc_common::{memo::DenseMemo, parse::split_sections};
+use aoc_lib::{color_eyre::eyre::Result, to_lines};
+
+// Every target is a suffix of the towel being checked,
+// so its length is enough to tell it apart from the others.
+
+fn find_available_patterns<'a>(
+    patterns: &[&'a str],
+    wants: impl Iterator<Item = &'a str>,
+) -> usize {
+    fn is_possible(target: &str, patterns: &[&str], memo: &mut DenseMemo<usize, bool>) -> bool {
+        if target.is_empty() {
+            return true;
+        }
+        memo.get_or_insert_with(target.len(), |memo, _| {
+            patterns
+                .iter()
+                .filter(|&pat| target.starts_with(pat))
+                .any(|pat| is_possible(&target[pat.len()..], patterns, memo))
+        })
+    }
+
+    let mut memo = DenseMemo::dense(0);
+    wants
+        .filter(|want| {
+            memo.reset(want.len() + 1);
+            is_possible(want, patterns, &mut memo)
+        })
+        .count()
+}
+
+/// The towel patterns, and the designs wanted.
+pub fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>)> {
+    let [patterns, wants] = split_sections(input)?;
+    Ok((patterns.split(", ").collect(), to_lines(wants).collect()))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (patterns, wants) = parse(input)?;
+
+    Ok(find_available_patterns(&patterns, wants.into_iter()))
+}
+
+fn find_all_available_patterns<'a>(
+    patterns: &[&'a str],
+    wants: impl Iterator<Item = &'a str>,
+) -> usize {
+    fn inner(want: &str, patterns: &[&str], memo: &mut DenseMemo<usize, usize>) -> usize {
+        if want.is_empty() {
+            return 1;
+        }
+        memo.get_or_insert_with(want.len(), |memo, _| {
+            patterns
+                .iter()
+                .map(|pat| {
+                    // somehow this is faster than doing filter + map or filter_map
+                    // also, using strip_prefix is slower
+                    #[allow(clippy::manual_strip)]
+                    if want.starts_with(pat) {
+                        inner(&want[pat.len()..], patterns, memo)
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        })
+    }
+
+    let mut memo = DenseMemo::dense(0);
+    wants
+        .map(|want| {
+            memo.reset(want.len() + 1);
+            inner(want, patterns, &mut memo)
+        })
+        .sum()
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (patterns, wants) = parse(input)?;
+
+    Ok(find_all_available_patterns(&patterns, wants.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 16);
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_common::search::{bfs, dijkstra};
+use aoc_lib::{
+    color_eyre::eyre::{OptionExt, Result},
+    grid::{Grid, Point, PointExt},
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// The racetrack, and where it starts and ends.
+pub fn parse(input: &str) -> Result<(Grid<'_>, Point, Point)> {
+    let input = input.trim();
+    let grid = Grid::for_str(input).ok_or_eyre("invalid format")?;
+
+    let find = |c| {
+        input
+            .bytes()
+            .position(|x| x == c)
+            .and_then(|idx| grid.idx_to_pos(idx))
+            .ok_or_eyre("invalid input")
+    };
+    Ok((grid, find(b'S')?, find(b'E')?))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (grid, start, end) = parse(input)?;
+
+    let baseline = bfs(
+        start,
+        |&pos| grid.orthogonal_pos(pos).filter(|&next| grid[next] != b'#'),
+        |&pos| pos == end,
+    )
+    .goal_cost()
+    .ok_or_eyre("no path to the end")?;
+
+    let res = (1..grid.height() - 1)
+        .into_par_iter()
+        .flat_map(|y| (1..grid.width() - 1).into_par_iter().map(move |x| (x, y)))
+        .filter(|&cheat| {
+            if grid[cheat] != b'#' {
+                return false;
+            }
+            bfs(
+                start,
+                |&pos| {
+                    grid.orthogonal_pos(pos)
+                        .filter(|&next| grid[next] != b'#' || next == cheat)
+                },
+                |&pos| pos == end,
+            )
+            .goal_cost()
+            .is_some_and(|d| baseline - d >= 100)
+        })
+        .count();
+
+    Ok(res)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (grid, start, end) = parse(input)?;
+
+    let baseline = bfs(
+        start,
+        |&pos| grid.orthogonal_pos(pos).filter(|&next| grid[next] != b'#'),
+        |&pos| pos == end,
+    )
+    .goal_cost()
+    .ok_or_eyre("no path to the end")?;
+
+    let res = (1..grid.height() - 1)
+        .into_par_iter()
+        .flat_map(|y| (1..grid.width() - 1).into_par_iter().map(move |x| (x, y)))
+        .filter(|&pos| grid[pos] != b'#')
+        .flat_map(|pos| {
+            (-20i64..=20).into_par_iter().flat_map(move |dx| {
+                let z = 20 - dx.abs();
+                (-z..=z)
+                    .into_par_iter()
+                    .map(move |dy| pos.add(&(dx, dy)))
+                    .filter(move |&target| {
+                        grid.get_pos(target).is_some_and(|&c| c != b'#') && target != pos
+                    })
+                    .map(move |target| (pos, target))
+            })
+        })
+        .filter(|&(cheat_from, cheat_to)| {
+            let d =
+                (cheat_from.0.abs_diff(cheat_to.0) + cheat_from.1.abs_diff(cheat_to.1)) as usize;
+
+            let paths = dijkstra(
+                start,
+                |&pos| {
+                    if pos == cheat_from {
+                        vec![(cheat_to, d)].into_iter()
+                    } else {
+                        grid.orthogonal_pos(pos)
+                            .filter(|&next| grid[next] != b'#')
+                            .map(|pos| (pos, 1))
+                            .collect::<Vec<_>>()
+                            .into_iter()
+                    }
+                },
+                |&pos| pos == end,
+            );
+            paths.goal_cost().is_some_and(|d| baseline - d >= 100)
+        })
+        .count();
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aoc_common::{grid_buf::GridBuf, maze, rng::Rng};
+    use aoc_lib::grid::Point;
+
+    use super::*;
+
+    static EX_INPUT: &str = "EXAMPLE 1 HERE";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 0);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 0);
+    }
+
+    /// How far along the only track from `S` to `E` each of its tiles is.
+    fn track(grid: &GridBuf<u8>) -> HashMap<Point, i64> {
+        let mut track = vec![grid.find(&b'S').unwrap()];
+        while let Some(next) = grid
+            .orthogonal_pos(*track.last().unwrap())
+            .find(|&pos| grid[pos] != b'#' && (track.len() < 2 || pos != track[track.len() - 2]))
+        {
+            track.push(next);
+        }
+        (0..).zip(track).map(|(i, pos)| (pos, i)).collect()
+    }
+
+    /// How many walls save at least 100 picoseconds when removed, straight from the track.
+    fn wall_cheats(input: &str) -> usize {
+        let grid = GridBuf::for_str(input).unwrap();
+        let along = track(&grid);
+        grid.iter()
+            .filter(|&(pos, &c)| {
+                let ends: Vec<_> = grid
+                    .orthogonal_pos(pos)
+                    .filter_map(|pos| along.get(&pos))
+                    .collect();
+                match (ends.iter().min(), ends.iter().max()) {
+                    (Some(&first), Some(&last)) => c == b'#' && last - first - 2 >= 100,
+                    _ => false,
+                }
+            })
+            .count()
+    }
+
+    /// How many cheats of up to `max_len` save at least 100 picoseconds, straight from the track.
+    fn long_cheats(input: &str, max_len: i64) -> usize {
+        let along = track(&GridBuf::for_str(input).unwrap());
+        along
+            .iter()
+            .flat_map(|from| along.iter().map(move |to| (from, to)))
+            .filter(|&((from, i), (to, j))| {
+                let len = (from.0.abs_diff(to.0) + from.1.abs_diff(to.1)) as i64;
+                len <= max_len && j - i - len >= 100
+            })
+            .count()
+    }
+
+    #[test]
+    fn random_racetrack() {
+        // long enough to save 100 picoseconds on, while small enough for part 2 to get through
+        let input = maze::racetrack(21, 21, &mut Rng::new(2));
+        assert_eq!(part1(&input).unwrap(), wall_cheats(&input));
+        assert_eq!(part2(&input).unwrap(), long_cheats(&input, 20));
+    }
+}
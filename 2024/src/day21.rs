@@ -0,0 +1,206 @@
+use aoc_common::{dir::Dir, memo::Memo};
+use aoc_lib::{
+    color_eyre::eyre::{Result, bail},
+    grid::Point,
+    to_lines,
+};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Action {
+    Move(Point),
+    Press(i64),
+}
+
+fn code_char_keypad_pos(c: u8) -> Point {
+    // 7 8 9
+    // 4 5 6
+    // 1 2 3
+    //   0 A
+    match c {
+        b'7'..=b'9' => ((c - b'7').into(), 3),
+        b'4'..=b'6' => ((c - b'4').into(), 2),
+        b'1'..=b'3' => ((c - b'1').into(), 1),
+        b'0' => (1, 0),
+        b'A' => (2, 0),
+        _ => unreachable!(),
+    }
+}
+
+fn dir_keypad_pos(key: Option<Dir>) -> Point {
+    //   ^ A
+    // < v >
+    match key {
+        None => (2, 1), // A button
+        Some(Dir::Left) => (0, 0),
+        Some(Dir::Up) => (1, 1),
+        Some(Dir::Down) => (1, 0),
+        Some(Dir::Right) => (2, 0),
+    }
+}
+
+fn robot_movements(
+    dirs: &[Action],
+    layers: usize,
+    memo: &mut Memo<(Vec<Action>, usize), i64>,
+) -> i64 {
+    dirs.iter()
+        .scan(dir_keypad_pos(None), |pos, &dir| {
+            let mut actions = Vec::new();
+            let mut move_pos = |pos: &mut Point, to_reach: Point, times_to_press| {
+                let d = (to_reach.0 - pos.0, to_reach.1 - pos.1);
+                if pos.0 == 0 && to_reach.1 == 1 {
+                    // prioritize going right over up if we were to hover over the gap
+                    actions.push(Action::Move((d.0, 0)));
+                    actions.push(Action::Move((0, d.1)));
+                } else if pos.1 == 1 && to_reach.0 == 0 {
+                    // prioritize going down over left if we were to hover over the gap
+                    actions.push(Action::Move((0, d.1)));
+                    actions.push(Action::Move((d.0, 0)));
+                } else {
+                    // otherwise just set our aciton to the direction we want to move in,
+                    // and let our parent figure out which way to move us first
+                    if d != (0, 0) {
+                        actions.push(Action::Move(d));
+                    }
+                }
+                actions.push(Action::Press(times_to_press));
+                *pos = to_reach;
+            };
+
+            match dir {
+                Action::Press(times) => {
+                    let to_reach = dir_keypad_pos(None);
+                    move_pos(pos, to_reach, times);
+                }
+                Action::Move(dir) => {
+                    let to_reach_hor = Dir::from_delta((dir.0.signum(), 0))
+                        .map(|key| (dir_keypad_pos(Some(key)), dir.0.abs()));
+
+                    // y grows upwards on the keypads, unlike with `Dir`
+                    let to_reach_ver = Dir::from_delta((0, -dir.1.signum()))
+                        .map(|key| (dir_keypad_pos(Some(key)), dir.1.abs()));
+
+                    // all of this to just either move horizontally or vertically first,
+                    // depending which key is closer.
+                    let ops = if to_reach_hor.is_some_and(|(hor, _)| {
+                        to_reach_ver.is_none_or(|(ver, _)| {
+                            let d1 = (hor.0 - pos.0, hor.1 - pos.1);
+                            let d2 = (ver.0 - pos.0, ver.1 - pos.1);
+                            d1 < d2
+                        })
+                    }) {
+                        [to_reach_hor, to_reach_ver]
+                    } else {
+                        [to_reach_ver, to_reach_hor]
+                    };
+
+                    for (to_reach, times) in ops.into_iter().flatten() {
+                        move_pos(pos, to_reach, times);
+                    }
+                }
+            }
+
+            let res = memo.get_or_insert_with((actions, layers), |memo, (actions, layers)| {
+                if *layers > 0 {
+                    robot_movements(actions, layers - 1, memo)
+                } else {
+                    actions.iter().fold(0, |acc, &curr| {
+                        acc + match curr {
+                            Action::Move((dx, dy)) => dx.abs() + dy.abs(),
+                            Action::Press(times) => times,
+                        }
+                    })
+                }
+            });
+            Some(res)
+        })
+        .sum()
+}
+
+/// Every door code, along with its numeric part.
+pub fn parse(input: &str) -> Result<Vec<(&str, i64)>> {
+    to_lines(input)
+        .map(|line| {
+            let Some(num) = line.strip_suffix('A').filter(|num| num.len() == 3) else {
+                bail!("invalid code {line:?}");
+            };
+            Ok((line, num.parse()?))
+        })
+        .collect()
+}
+
+fn find_complexity(
+    (line, val): (&str, i64),
+    layers: usize,
+    memo: &mut Memo<(Vec<Action>, usize), i64>,
+) -> i64 {
+    line.bytes()
+        .map(code_char_keypad_pos)
+        .scan(code_char_keypad_pos(b'A'), |pos, to_reach| {
+            let mut res = Vec::new();
+            let d = (to_reach.0 - pos.0, to_reach.1 - pos.1);
+            if pos.1 == 0 && to_reach.0 == 0 {
+                // prioritize going up over left if we were to hover over the gap
+                if d.1 != 0 {
+                    res.push(Action::Move((0, d.1)));
+                }
+                if d.0 != 0 {
+                    res.push(Action::Move((d.0, 0)));
+                }
+            } else if pos.0 == 0 && to_reach.1 == 0 {
+                // prioritize going right over down if we were to hover over the gap
+                if d.0 != 0 {
+                    res.push(Action::Move((d.0, 0)));
+                }
+                if d.1 != 0 {
+                    res.push(Action::Move((0, d.1)));
+                }
+            } else {
+                res.push(Action::Move(d));
+            }
+            res.push(Action::Press(1));
+            *pos = to_reach;
+            Some(robot_movements(&res, layers - 1, memo))
+        })
+        .sum::<i64>()
+        * val
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    let mut memo = Memo::new();
+    Ok(parse(input)?
+        .into_iter()
+        .map(|code| find_complexity(code, 2, &mut memo))
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let mut memo = Memo::new();
+    Ok(parse(input)?
+        .into_iter()
+        .map(|code| find_complexity(code, 25, &mut memo))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT: &str = r#"
+029A
+980A
+179A
+456A
+379A
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 126384);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT).unwrap(), 154115708116294);
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::{color_eyre::eyre::Result, to_lines};
+
+fn calc_next(num: i64) -> i64 {
+    let next = num;
+    let next = (next ^ (next * 64)) % 16777216;
+    let next = (next ^ (next / 32)) % 16777216;
+    let next = (next ^ (next * 2048)) % 16777216;
+    next
+}
+
+fn calc_steps(num: i64, steps: usize) -> i64 {
+    let mut res = num;
+    for _ in 0..steps {
+        res = calc_next(res);
+    }
+    res
+}
+
+/// Every buyer's initial secret number.
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    to_lines(input).map(|num| Ok(num.parse()?)).collect()
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    Ok(parse(input)?
+        .into_iter()
+        .map(|num| calc_steps(num, 2000))
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let seqs: Vec<_> = parse(input)?
+        .into_iter()
+        .map(|mut num| {
+            let mut prev = num % 10;
+            let mut seq = Vec::with_capacity(2000);
+            for _ in 0..2000 {
+                let next = calc_next(num);
+                let now = next % 10;
+                let d = now - prev;
+                seq.push((now, d));
+                num = next;
+                prev = now;
+            }
+            seq
+        })
+        .collect();
+    let changes: Vec<_> = seqs
+        .into_iter()
+        .map(|seq| {
+            let mut res = HashMap::new();
+            for w in seq.windows(4) {
+                let z: [i64; 4] = std::array::from_fn(|i| w[i].1);
+                if !res.contains_key(&z) {
+                    res.insert(z, w[3].0);
+                }
+            }
+            res
+        })
+        .collect();
+    let all_seqs: HashSet<_> = changes
+        .iter()
+        .flat_map(|changes| changes.keys().copied())
+        .collect();
+
+    let res = all_seqs
+        .into_iter()
+        .map(|seq| {
+            changes
+                .iter()
+                .map(|changes| changes.get(&seq).copied().unwrap_or(0))
+                .sum()
+        })
+        .max()
+        .unwrap();
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT_1: &str = r#"
+1
+10
+100
+2024
+"#;
+
+    static EX_INPUT_2: &str = r#"
+1
+2
+3
+2024
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT_1).unwrap(), 37327623);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT_2).unwrap(), 23);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    answer::Answer,
+    graph::{Graph, dot_requested},
+    intern::{Id, Interner},
+    parse::edge,
+};
+use aoc_lib::{color_eyre::eyre::Result, to_lines};
+
+/// The computers' names, every computer's connections, indexed by id,
+/// and the computers on the left of the connections, in order.
+pub fn parse(input: &str) -> Result<(Interner<'_>, Vec<HashSet<Id>>, Vec<Id>)> {
+    let mut names = Interner::new();
+    let mut conns = Vec::<HashSet<Id>>::new();
+    let mut puters = Vec::new();
+    for line in to_lines(input) {
+        let (a, b) = edge(line)?;
+        let [a, b] = [a, b].map(|puter| names.intern(puter));
+        conns.resize(names.len(), HashSet::new());
+        conns[a as usize].insert(b);
+        conns[b as usize].insert(a);
+        puters.push(a);
+    }
+    Ok((names, conns, puters))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (names, conns, _) = parse(input)?;
+    if dot_requested() {
+        let mut graph = Graph::undirected();
+        for (a, name) in names.iter() {
+            for &b in conns[a as usize].iter().filter(|&&b| a < b) {
+                graph.connect(name, names.name(b), ());
+            }
+        }
+        eprintln!("{}", graph.dot());
+    }
+    let conns = &conns;
+    let triples: HashSet<[Id; 3]> = (0..names.len() as Id)
+        .flat_map(|a| {
+            conns[a as usize].iter().flat_map(move |&b| {
+                conns[b as usize]
+                    .iter()
+                    .filter(move |&&c| c != a && conns[c as usize].contains(&a))
+                    .map(move |&c| {
+                        let mut res = [a, b, c];
+                        res.sort();
+                        res
+                    })
+            })
+        })
+        .collect();
+    Ok(triples
+        .into_iter()
+        .filter(|triple| {
+            triple
+                .iter()
+                .any(|&puter| names.name(puter).starts_with('t'))
+        })
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let (names, mut conns, puters) = parse(input)?;
+    for &puter in &puters {
+        loop {
+            let this_conns = &conns[puter as usize];
+            // get the computer with the most and the least common connections with us
+            let (min, max) = this_conns.iter().fold(
+                ((Id::MAX, usize::MAX), (Id::MAX, usize::MIN)),
+                |mut res @ ((_, min), (_, max)), &curr| {
+                    let cnt = conns[curr as usize].intersection(this_conns).count();
+
+                    if cnt < min {
+                        res.0 = (curr, cnt);
+                    }
+                    if cnt > max {
+                        res.1 = (curr, cnt);
+                    }
+
+                    res
+                },
+            );
+
+            if min.1 != max.1 && min.0 != max.0 {
+                // and remove it
+                conns[puter as usize].remove(&min.0);
+                conns[min.0 as usize].remove(&puter);
+            } else {
+                // unless every computer has the same amount of connections in common
+                break;
+            }
+        }
+    }
+
+    let tail = conns
+        .into_iter()
+        .zip(names.iter())
+        .map(|(tail, (head, _))| {
+            let mut tail: Vec<_> = tail.into_iter().map(|puter| names.name(puter)).collect();
+            tail.push(names.name(head));
+            tail.sort_unstable();
+            tail
+        })
+        .max_by_key(|group| group.len())
+        .unwrap();
+
+    Ok(tail.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EX_INPUT_1: &str = r#"
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+"#;
+
+    static EX_INPUT_2: &str = r#"
+ka-co
+ta-co
+de-co
+ta-ka
+de-ta
+ka-de
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT_1).unwrap(), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EX_INPUT_2).unwrap(), "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,274 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use aoc_common::{
+    answer::Answer,
+    graph::{Graph, dot_requested},
+    intern::{Id, Interner},
+    parse::{labeled, split_sections, words},
+};
+use aoc_lib::{
+    color_eyre::eyre::{Result, bail},
+    to_lines,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Gate {
+    Hole,
+    Output(bool),
+    And(Id, Id),
+    Or(Id, Id),
+    Xor(Id, Id),
+}
+
+/// The wires, and the gate driving each of them, indexed by the wire's id.
+#[derive(Default)]
+struct Circuit<'s> {
+    wires: Interner<'s>,
+    gates: Vec<Option<Gate>>,
+}
+
+impl<'s> Circuit<'s> {
+    fn insert(&mut self, wire: &'s str, gate: Gate) {
+        let wire = self.wires.intern(wire);
+        self.gates.resize(self.wires.len(), None);
+        assert!(self.gates[wire as usize].replace(gate).is_none());
+    }
+
+    fn insert_gate(&mut self, a: &'s str, op: &str, b: &'s str, output: &'s str) {
+        let res = match op {
+            "AND" => Gate::And,
+            "OR" => Gate::Or,
+            "XOR" => Gate::Xor,
+            _ => unreachable!(),
+        };
+        let res = res(self.wires.intern(a), self.wires.intern(b));
+        self.insert(output, res);
+    }
+
+    fn get_gate_val(&mut self, gate: Id) -> Option<bool> {
+        match self.gates[gate as usize]? {
+            Gate::Hole => None,
+            Gate::Output(val) => Some(val),
+            Gate::And(a, b) => {
+                self.gates[gate as usize] = Some(Gate::Hole);
+                let a = self.get_gate_val(a)?;
+                let b = self.get_gate_val(b)?;
+                let res = a & b;
+                self.gates[gate as usize] = Some(Gate::Output(res));
+                Some(res)
+            }
+            Gate::Or(a, b) => {
+                self.gates[gate as usize] = Some(Gate::Hole);
+                let a = self.get_gate_val(a)?;
+                let b = self.get_gate_val(b)?;
+                let res = a | b;
+                self.gates[gate as usize] = Some(Gate::Output(res));
+                Some(res)
+            }
+            Gate::Xor(a, b) => {
+                self.gates[gate as usize] = Some(Gate::Hole);
+                let a = self.get_gate_val(a)?;
+                let b = self.get_gate_val(b)?;
+                let res = a ^ b;
+                self.gates[gate as usize] = Some(Gate::Output(res));
+                Some(res)
+            }
+        }
+    }
+
+    fn get_reg_val(&mut self, reg: char) -> Option<u64> {
+        let output_gates = {
+            let mut res: Vec<_> = self
+                .wires
+                .iter()
+                .filter(|&(wire, name)| {
+                    name.starts_with(reg) && self.gates[wire as usize].is_some()
+                })
+                .collect();
+            res.sort_unstable_by_key(|&(_, name)| Reverse(name));
+            res
+        };
+        output_gates
+            .into_iter()
+            .map(|(gate, _)| self.get_gate_val(gate))
+            .fold(Some(0), |acc, curr| {
+                Some((acc? << 1) | (if curr? { 1 } else { 0 }))
+            })
+    }
+}
+
+/// An input wire and its initial value.
+pub type InputDesc<'s> = (&'s str, bool);
+
+/// `a OP b -> out` as `(a, op, b, out)`.
+pub type GateDesc<'s> = (&'s str, &'s str, &'s str, &'s str);
+
+fn parse_gate(line: &str) -> Result<GateDesc<'_>> {
+    let [a, op, b, _, output] = words(line)?;
+    if !["AND", "OR", "XOR"].contains(&op) {
+        bail!("invalid gate {op:?}");
+    }
+    Ok((a, op, b, output))
+}
+
+/// The initial values of the input wires, and every gate.
+pub fn parse(input: &str) -> Result<(Vec<InputDesc<'_>>, Vec<GateDesc<'_>>)> {
+    let [inputs, gates_desc] = split_sections(input)?;
+    let inputs = to_lines(inputs)
+        .map(|line| {
+            let (wire, val) = labeled(line)?;
+            Ok((wire, val == "1"))
+        })
+        .collect::<Result<_>>()?;
+    let gates = to_lines(gates_desc)
+        .map(parse_gate)
+        .collect::<Result<_>>()?;
+    Ok((inputs, gates))
+}
+
+fn with_inputs<'s>(inputs: &[InputDesc<'s>]) -> Circuit<'s> {
+    let mut circuit = Circuit::default();
+    for &(wire, val) in inputs {
+        circuit.insert(wire, Gate::Output(val));
+    }
+    circuit
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    let (inputs, gates) = parse(input)?;
+    let mut circuit = with_inputs(&inputs);
+    for (a, op, b, output) in gates {
+        circuit.insert_gate(a, op, b, output);
+    }
+
+    Ok(circuit.get_reg_val('z').unwrap())
+}
+
+// I just looked at the input as a graph (run with --dot) and spotted the misplaced binary adders.
+// Not the cleanest solution, but definitely faster than coming up with a programmatic solution
+pub fn part2(input: &str) -> Result<Answer> {
+    let (inputs, gates) = parse(input)?;
+
+    let mut to_swap = [
+        ["z17", "cmv"],
+        ["z23", "rmj"],
+        ["z30", "rdg"],
+        ["btb", "mwp"],
+    ];
+
+    let swapped = |output| {
+        to_swap
+            .iter()
+            .find_map(|&[x, y]| {
+                if x == output {
+                    Some(y)
+                } else if y == output {
+                    Some(x)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(output)
+    };
+
+    if dot_requested() {
+        let mut graph = Graph::<&str>::directed();
+        let mut ops = HashMap::new();
+        for &(a, op, b, output) in &gates {
+            let output = swapped(output);
+            ops.insert(output, op);
+            graph.connect(a, output, ());
+            graph.connect(b, output, ());
+        }
+        let dot = graph.dot().node_label(|_, wire| match ops.get(wire) {
+            Some(op) => format!("{wire}_{op}"),
+            None => wire.to_string(),
+        });
+        eprintln!("{dot}");
+    }
+
+    let mut circuit = with_inputs(&inputs);
+
+    let x = circuit.get_reg_val('x').unwrap();
+    let y = circuit.get_reg_val('y').unwrap();
+    let expected = x + y;
+
+    for (a, op, b, output) in gates {
+        circuit.insert_gate(a, op, b, swapped(output));
+    }
+
+    let actual = circuit.get_reg_val('z').unwrap();
+    assert_eq!(actual, expected);
+
+    let res = to_swap.as_flattened_mut();
+    res.sort();
+    Ok(res.to_vec().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = include_str!("../inputs/day24/input");
+
+    static EX_INPUT: &str = r#"
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+"#;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EX_INPUT).unwrap(), 2024);
+    }
+
+    // fake test-case because I can't be bothered setting one up
+    #[test]
+    fn part2_input() {
+        assert_eq!(part2(INPUT).unwrap(), "btb,cmv,mwp,rdg,rmj,z17,z23,z30");
+    }
+}
@@ -0,0 +1,29 @@
+//! Advent of Code 2024 solutions, one module per day.
+//!
+//! Every day exposes `part1` and `part2`, which take the puzzle input, along with
+//! its parser and types where it has any. The binaries in `src/bin` run them on the inputs.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
@@ -0,0 +1,35 @@
+//! Writes a random maze to stdout, in the input format of the day it's for.
+//!
+//! `cargo run --example maze -- <racetrack|branching|slopes|pipes> <width> <height> <seed> [loops]`
+//!
+//! Pipe mazes come with their answers, which are written to stderr.
+
+use aoc_common::{maze, pipes, rng::Rng};
+use aoc_lib::color_eyre::eyre::{OptionExt, Result, bail};
+
+fn main() -> Result<()> {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    let [kind, width, height, seed, rest @ ..] = &args[..] else {
+        bail!("usage: maze <racetrack|branching|slopes|pipes> <width> <height> <seed> [loops]");
+    };
+    let (width, height) = (width.parse()?, height.parse()?);
+    let mut rng = Rng::new(seed.parse()?);
+    let loops = || -> Result<f64> {
+        let loops = rest.first().ok_or_eyre("missing the loop density")?;
+        Ok(loops.parse()?)
+    };
+
+    let maze = match kind.as_str() {
+        "racetrack" => maze::racetrack(width, height, &mut rng),
+        "branching" => maze::branching(width, height, loops()?, &mut rng),
+        "slopes" => maze::slopes(width, height, loops()?, &mut rng),
+        "pipes" => {
+            let maze = pipes::pipe_maze(width, height, &mut rng);
+            eprintln!("farthest: {}\nenclosed: {}", maze.farthest, maze.enclosed);
+            maze.input
+        }
+        _ => bail!("unknown kind of maze: {kind}"),
+    };
+    print!("{maze}");
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Times every day of both years and writes the tables into their readmes.
+//!
+//! `cargo run --release --example report -- [runs]`,
+//! or `cargo timings [runs]` from the repository's root.
+//! See [`aoc_common::report`].
+
+use std::{fs, path::Path, process::Command};
+
+use aoc_common::report;
+use aoc_lib::color_eyre::eyre::{Result, bail};
+
+const YEARS: [&str; 2] = ["2023", "2024"];
+
+fn main() -> Result<()> {
+    let runs = match std::env::args().nth(1) {
+        Some(runs) => runs.parse::<usize>()?.to_string(),
+        None => "5".to_string(),
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for year in YEARS {
+        let dir = root.join(year);
+        cargo(&dir, &["build", "--release", "--bins"])?;
+
+        let mut days = Vec::new();
+        for entry in fs::read_dir(dir.join("src/bin"))? {
+            let path = entry?.path();
+            days.push(path.file_stem().unwrap().to_string_lossy().into_owned());
+        }
+        days.sort();
+
+        let mut timings = Vec::new();
+        for day in days {
+            eprintln!("{year} {day}");
+            let args = [
+                "run",
+                "--release",
+                "-q",
+                "--bin",
+                &day,
+                "--",
+                "--repeat",
+                &runs,
+            ];
+            // one broken day shouldn't keep the others from being timed
+            let day_timings = match cargo(&dir, &args) {
+                Ok(stderr) => report::timings(day.trim_start_matches("day"), &stderr),
+                Err(err) => {
+                    eprintln!("skipping {year} {day}: {err}");
+                    continue;
+                }
+            };
+            if day_timings.is_empty() {
+                bail!("{year} {day} didn't report how long it took");
+            }
+            timings.extend(day_timings);
+        }
+
+        let path = dir.join("readme.md");
+        let readme = fs::read_to_string(&path).unwrap_or_default();
+        let table = report::table(&timings, &report::previous(&readme));
+        fs::write(&path, report::splice(&readme, &table))?;
+    }
+    Ok(())
+}
+
+/// Runs cargo in `dir`, returning its stderr, which is where `--repeat` reports the timings.
+fn cargo(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("cargo").args(args).current_dir(dir).output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    if !output.status.success() {
+        bail!(
+            "cargo {} failed in {}:\n{stderr}",
+            args.join(" "),
+            dir.display()
+        );
+    }
+    Ok(stderr)
+}
@@ -0,0 +1,189 @@
+//! What the parts answer with, when it's not just a number.
+//!
+//! Every part's answer is shown through [`Answer`], so the runner, the `.expected` files and the tests
+//! all see them the same way: `1,2` for a coordinate, `ab,cd` for a list, and so on.
+//! Answers compare equal when they're shown the same, so a test can check any of them against a string.
+//!
+//! Some puzzles answer with capital letters drawn in a grid, which [`Answer::Art`] reads with [`ocr`].
+
+use std::fmt;
+
+use aoc_lib::grid::Point;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i128),
+    /// Unsigned, for counts that can outgrow [`Answer::Int`].
+    UInt(u128),
+    Str(String),
+    Point(Point),
+    /// Shown separated by commas.
+    List(Vec<Answer>),
+    /// Letters drawn with `#` on `.`, one line per row, shown as the letters if they can be read.
+    Art(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Str(s) => f.write_str(s),
+            Answer::Point((x, y)) => write!(f, "{x},{y}"),
+            Answer::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
+            Answer::Art(art) => match ocr(art) {
+                Some(letters) => f.write_str(&letters),
+                // starting on a line of its own, so it's not thrown off by what's printed before it
+                None => write!(f, "\n{}", art.trim_end()),
+            },
+        }
+    }
+}
+
+impl<T: Clone + Into<Answer>> PartialEq<T> for Answer {
+    fn eq(&self, other: &T) -> bool {
+        let other: Answer = other.clone().into();
+        format!("{self}") == format!("{other}")
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n.into())
+            }
+        }
+    )*};
+}
+
+from_int!(i32, u32, i64, u64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::UInt(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(pos: Point) -> Self {
+        Answer::Point(pos)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(items: Vec<T>) -> Self {
+        Answer::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+const LETTERS: &str = "ABCEFGHIJKLOPRSUZ";
+
+/// Every letter in [`LETTERS`], 4 columns wide and followed by an empty one.
+const FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#....#",
+    "#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#...#.",
+    "####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#..#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#.#...",
+    "#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##..####",
+];
+
+/// Reads the letters drawn in `art`, in the font the puzzles use: 6 rows tall, 4 columns wide,
+/// with a column between letters. Any character other than `#` counts as empty.
+pub fn ocr(art: &str) -> Option<String> {
+    let rows: Vec<_> = art.lines().filter(|row| !row.trim().is_empty()).collect();
+    if rows.len() != FONT.len() {
+        return None;
+    }
+    let width = rows.iter().map(|row| row.len()).max()?;
+    (0..width)
+        .step_by(5)
+        .map(|x| {
+            let glyph = glyph(&rows, x);
+            let letter = (0..LETTERS.len()).position(|i| self::glyph(&FONT, i * 5) == glyph)?;
+            Some(LETTERS.as_bytes()[letter] as char)
+        })
+        .collect()
+}
+
+/// The 4 columns of `rows` starting at `x`, as bits.
+fn glyph(rows: &[&str], x: usize) -> u32 {
+    let lit = |row: &str, x| row.as_bytes().get(x) == Some(&b'#');
+    rows.iter()
+        .flat_map(|row| (x..x + 4).map(move |x| lit(row, x)))
+        .fold(0, |bits, lit| bits << 1 | u32::from(lit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shown() {
+        assert_eq!(Answer::from(42).to_string(), "42");
+        assert_eq!(Answer::from((46, 18)).to_string(), "46,18");
+        assert_eq!(Answer::from(vec!["co", "de", "ka"]).to_string(), "co,de,ka");
+        assert_eq!(Answer::from(vec![4, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn compared() {
+        assert_eq!(Answer::from(42u64), 42);
+        assert_eq!(Answer::from(42u128), 42);
+        assert_eq!(Answer::from((46, 18)), "46,18");
+        assert_eq!(Answer::from(vec![4, 6]), Answer::from("4,6"));
+        assert_ne!(Answer::from(42), 43);
+    }
+
+    #[test]
+    fn letters() {
+        let font = FONT.join("\n");
+        assert_eq!(ocr(&font).as_deref(), Some(LETTERS));
+
+        let art = "
+#..#.####.###..
+#..#.#....#..#.
+####.###..#..#.
+#..#.#....###..
+#..#.#....#....
+#..#.####.#....
+";
+        assert_eq!(ocr(art).as_deref(), Some("HEP"));
+        assert_eq!(Answer::Art(art.to_string()), "HEP");
+
+        let unreadable = art.replace("####.###", "###..###");
+        assert_eq!(ocr(&unreadable), None);
+        assert_eq!(
+            Answer::Art(unreadable.clone()).to_string(),
+            format!("\n{}", unreadable.trim_end())
+        );
+    }
+}
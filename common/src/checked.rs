@@ -0,0 +1,115 @@
+//! Arithmetic for the spots where answers can outgrow their integers.
+//!
+//! These are the plain operators normally, which silently wrap around in release builds.
+//! With the `checked` feature, e.g. `cargo run --release --features checked --bin day07`,
+//! they panic on overflow instead, pointing at the line which overflowed.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+pub trait Int: Copy + Display + Add<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {$(
+        impl Int for $t {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                self.checked_pow(exp)
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                self.pow(exp)
+            }
+        }
+    )*};
+}
+
+impl_int!(u32, u64, u128, usize, i32, i64, i128);
+
+#[track_caller]
+fn overflowed(a: impl Display, op: &str, b: impl Display) -> ! {
+    panic!("overflow computing {a} {op} {b}")
+}
+
+#[track_caller]
+pub fn add<T: Int>(a: T, b: T) -> T {
+    if cfg!(feature = "checked") {
+        match a.checked_add(b) {
+            Some(res) => res,
+            None => overflowed(a, "+", b),
+        }
+    } else {
+        a + b
+    }
+}
+
+#[track_caller]
+pub fn mul<T: Int>(a: T, b: T) -> T {
+    if cfg!(feature = "checked") {
+        match a.checked_mul(b) {
+            Some(res) => res,
+            None => overflowed(a, "*", b),
+        }
+    } else {
+        a * b
+    }
+}
+
+#[track_caller]
+pub fn pow<T: Int>(base: T, exp: u32) -> T {
+    if cfg!(feature = "checked") {
+        match base.checked_pow(exp) {
+            Some(res) => res,
+            None => overflowed(base, "^", exp),
+        }
+    } else {
+        base.pow(exp)
+    }
+}
+
+#[track_caller]
+pub fn sum<T: Int>(iter: impl IntoIterator<Item = T>) -> T {
+    let mut res = T::ZERO;
+    for n in iter {
+        res = add(res, n);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(add(2u64, 3), 5);
+        assert_eq!(mul(u64::MAX / 2, 2), u64::MAX - 1);
+        assert_eq!(pow(10u64, 19), 10_000_000_000_000_000_000);
+        assert_eq!(sum([1u128 << 100, 1 << 100]), 1 << 101);
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "overflow computing 9223372036854775807 * 3")]
+    fn overflow() {
+        mul(u64::MAX / 2, 3);
+    }
+}
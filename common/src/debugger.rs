@@ -0,0 +1,387 @@
+//! Interactive step-through debugger for the simulation days, started by running them with `--step`.
+//!
+//! A day opts in by implementing [`Simulation`] for its state and handing it to [`run`],
+//! which shows it in the terminal and lets you move through it:
+//!
+//! | key                  | action                               |
+//! |----------------------|--------------------------------------|
+//! | `→` `l` `space`      | step forward                         |
+//! | `←` `h`              | step back                            |
+//! | `r`                  | run forward, or pause                |
+//! | `R`                  | run backwards, or pause              |
+//! | `+` `-`              | run faster or slower                 |
+//! | `g`                  | jump to a step, typed in and `enter` |
+//! | `home` `end`         | jump to the start or the end         |
+//! | `q` `esc`            | quit, letting the day carry on       |
+//!
+//! Some simulations never end, so jumping to the end stops at the next key press.
+//!
+//! Stepping back doesn't need the simulation to be reversible: every [`CHECKPOINT_EVERY`] steps
+//! the state is cloned, and earlier steps are replayed from the closest checkpoint before them.
+
+use std::{
+    fmt::Write as _,
+    io::{self, IsTerminal, Write},
+    time::Duration,
+};
+
+use aoc_lib::{color_eyre::eyre::Result, grid::Point};
+use crossterm::{
+    ExecutableCommand, QueueableCommand, cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType},
+};
+
+/// State which advances one step at a time and can be drawn as text.
+pub trait Simulation: Clone {
+    /// Advances the state by one step, returning `false` without changing it once it's over.
+    fn step(&mut self) -> bool;
+
+    /// The current state, one line per row.
+    fn render(&self) -> String;
+
+    /// Counters shown in the status line, like how many cells have been visited so far.
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// The position in the rendered state to keep in view when it doesn't fit in the terminal.
+    fn focus(&self) -> Option<Point> {
+        None
+    }
+}
+
+/// Whether the current binary was invoked with `--step`,
+/// in which case the simulation days open their states in the debugger.
+pub fn step_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--step")
+}
+
+/// How many steps apart the states kept for stepping back are.
+pub const CHECKPOINT_EVERY: usize = 64;
+
+/// A simulation which can be moved to any of the steps it has been through.
+#[derive(Debug, Clone)]
+struct Timeline<S> {
+    /// The states at every multiple of [`CHECKPOINT_EVERY`] steps reached so far.
+    checkpoints: Vec<S>,
+    current: S,
+    step: usize,
+    /// The last step, once the simulation got there.
+    end: Option<usize>,
+}
+
+impl<S: Simulation> Timeline<S> {
+    fn new(start: S) -> Self {
+        Timeline {
+            checkpoints: vec![start.clone()],
+            current: start,
+            step: 0,
+            end: None,
+        }
+    }
+
+    fn forward(&mut self) -> bool {
+        if self.end == Some(self.step) {
+            return false;
+        }
+        if !self.current.step() {
+            self.end = Some(self.step);
+            return false;
+        }
+        self.step += 1;
+        if self.step == self.checkpoints.len() * CHECKPOINT_EVERY {
+            self.checkpoints.push(self.current.clone());
+        }
+        true
+    }
+
+    fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.jump(self.step - 1);
+        true
+    }
+
+    /// Moves to `target`, or as close to it as the simulation goes.
+    fn jump(&mut self, target: usize) {
+        let checkpoint = (target / CHECKPOINT_EVERY).min(self.checkpoints.len() - 1);
+        if target < self.step || checkpoint * CHECKPOINT_EVERY > self.step {
+            self.current = self.checkpoints[checkpoint].clone();
+            self.step = checkpoint * CHECKPOINT_EVERY;
+        }
+        while self.step < target && self.forward() {}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Paused,
+    Running,
+    Reversing,
+    /// Typing in the step to jump to.
+    Jumping,
+}
+
+/// Restores the terminal when dropped, even when unwinding from a panic.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        let guard = RawTerminal;
+        io::stdout()
+            .execute(terminal::EnterAlternateScreen)?
+            .execute(cursor::Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = io::stdout()
+            .execute(cursor::Show)
+            .and_then(|out| out.execute(terminal::LeaveAlternateScreen));
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Shows `sim` in the terminal, letting you step through it until you quit.
+/// Does nothing if stdout isn't a terminal.
+pub fn run<S: Simulation>(sim: S) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        return Ok(());
+    }
+    let _terminal = RawTerminal::enter()?;
+    let mut timeline = Timeline::new(sim);
+    let mut mode = Mode::Paused;
+    let mut delay = Duration::from_millis(50);
+    let mut typed = String::new();
+
+    loop {
+        draw(&timeline, mode, &typed, delay)?;
+
+        let running = matches!(mode, Mode::Running | Mode::Reversing);
+        if running && !event::poll(delay)? {
+            let moved = match mode {
+                Mode::Running => timeline.forward(),
+                _ => timeline.back(),
+            };
+            if !moved {
+                mode = Mode::Paused;
+            }
+            continue;
+        }
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+
+        if mode == Mode::Jumping {
+            match code {
+                KeyCode::Char(c) if c.is_ascii_digit() => typed.push(c),
+                KeyCode::Backspace => {
+                    typed.pop();
+                }
+                KeyCode::Enter => {
+                    if let Ok(target) = typed.parse() {
+                        timeline.jump(target);
+                    }
+                    typed.clear();
+                    mode = Mode::Paused;
+                }
+                KeyCode::Esc => {
+                    typed.clear();
+                    mode = Mode::Paused;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Right | KeyCode::Char('l' | ' ') => {
+                mode = Mode::Paused;
+                timeline.forward();
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                mode = Mode::Paused;
+                timeline.back();
+            }
+            KeyCode::Char('r') => {
+                mode = if mode == Mode::Running {
+                    Mode::Paused
+                } else {
+                    Mode::Running
+                };
+            }
+            KeyCode::Char('R') => {
+                mode = if mode == Mode::Reversing {
+                    Mode::Paused
+                } else {
+                    Mode::Reversing
+                };
+            }
+            KeyCode::Char('+') => delay = (delay / 2).max(Duration::from_millis(1)),
+            KeyCode::Char('-') => delay = (delay * 2).min(Duration::from_secs(2)),
+            KeyCode::Char('g') => mode = Mode::Jumping,
+            KeyCode::Home => timeline.jump(0),
+            KeyCode::End => run_to_end(&mut timeline)?,
+            _ => {}
+        }
+    }
+}
+
+/// Steps `timeline` forward until it ends or a key is pressed, which is then handled as usual.
+fn run_to_end<S: Simulation>(timeline: &mut Timeline<S>) -> Result<()> {
+    const STEPS_BETWEEN_POLLS: usize = 1024;
+    loop {
+        for _ in 0..STEPS_BETWEEN_POLLS {
+            if !timeline.forward() {
+                return Ok(());
+            }
+        }
+        if event::poll(Duration::ZERO)? {
+            return Ok(());
+        }
+    }
+}
+
+/// The first of `len` lines or columns to show in a view `size` long, keeping `focus` centered.
+fn view_start(len: usize, size: usize, focus: Option<i64>) -> usize {
+    match focus {
+        Some(focus) if len > size => (focus.max(0) as usize)
+            .saturating_sub(size / 2)
+            .min(len - size),
+        _ => 0,
+    }
+}
+
+fn draw<S: Simulation>(
+    timeline: &Timeline<S>,
+    mode: Mode,
+    typed: &str,
+    delay: Duration,
+) -> Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, rows) = (width as usize, height.saturating_sub(1) as usize);
+
+    let rendered = timeline.current.render();
+    let lines: Vec<_> = rendered.lines().collect();
+    let line_len = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let focus = timeline.current.focus();
+    let top = view_start(lines.len(), rows, focus.map(|(_, y)| y));
+    let left = view_start(line_len, width, focus.map(|(x, _)| x));
+
+    let mut out = io::stdout().lock();
+    for (row, line) in lines.iter().skip(top).take(rows).enumerate() {
+        let visible: String = line.chars().skip(left).take(width).collect();
+        out.queue(cursor::MoveTo(0, row as u16))?
+            .queue(Print(visible))?
+            .queue(terminal::Clear(ClearType::UntilNewLine))?;
+    }
+    out.queue(cursor::MoveTo(0, lines.len().min(rows) as u16))?
+        .queue(terminal::Clear(ClearType::FromCursorDown))?;
+
+    let mut status = format!(" step {}", timeline.step);
+    if let Some(end) = timeline.end {
+        write!(status, "/{end}")?;
+    }
+    status += match mode {
+        Mode::Paused => "  paused",
+        Mode::Running => "  running",
+        Mode::Reversing => "  reversing",
+        Mode::Jumping => "  jump to: ",
+    };
+    if mode == Mode::Jumping {
+        status += typed;
+    } else {
+        write!(status, " ({delay:?}/step)")?;
+    }
+    for (name, value) in timeline.current.counters() {
+        write!(status, "  {name}: {value}")?;
+    }
+    status += "  | ←→ step  r run  R reverse  +- speed  g jump  q quit";
+    let status: String = status.chars().take(width).collect();
+    out.queue(cursor::MoveTo(0, rows as u16))?
+        .queue(SetAttribute(Attribute::Reverse))?
+        .queue(Print(format!("{status:width$}")))?
+        .queue(SetAttribute(Attribute::Reset))?;
+
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to `end`, one at a time.
+    #[derive(Debug, Clone)]
+    struct Counter {
+        n: usize,
+        end: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.n == self.end {
+                return false;
+            }
+            self.n += 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            self.n.to_string()
+        }
+    }
+
+    #[test]
+    fn timeline() {
+        let mut timeline = Timeline::new(Counter { n: 0, end: 200 });
+        assert!(!timeline.back());
+
+        for _ in 0..150 {
+            assert!(timeline.forward());
+        }
+        assert_eq!(timeline.checkpoints.len(), 3);
+        assert!(timeline.back());
+        assert_eq!((timeline.step, timeline.current.n), (149, 149));
+
+        timeline.jump(10);
+        assert_eq!((timeline.step, timeline.current.n), (10, 10));
+        timeline.jump(190);
+        assert_eq!((timeline.step, timeline.current.n), (190, 190));
+        assert_eq!(timeline.end, None);
+
+        timeline.jump(usize::MAX);
+        assert_eq!((timeline.step, timeline.current.n), (200, 200));
+        assert_eq!(timeline.end, Some(200));
+        assert!(!timeline.forward());
+        assert!(timeline.back());
+        assert_eq!(timeline.current.n, 199);
+    }
+
+    #[test]
+    fn view() {
+        assert_eq!(view_start(10, 20, Some(5)), 0);
+        assert_eq!(view_start(100, 20, None), 0);
+        assert_eq!(view_start(100, 20, Some(5)), 0);
+        assert_eq!(view_start(100, 20, Some(50)), 40);
+        assert_eq!(view_start(100, 20, Some(95)), 80);
+    }
+}
@@ -0,0 +1,274 @@
+//! Compass directions on a grid where `y` grows downwards, like it does in puzzle inputs.
+//!
+//! Both [`Dir`] and [`Dir8`] can index arrays with one slot per direction,
+//! which makes them handy for per-direction state, e.g. `[bool; 4]` instead of `HashSet<Dir>`.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use aoc_lib::grid::Point;
+
+/// One of the 4 orthogonal directions, ordered clockwise starting from [`Dir::Up`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn idx(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_idx(idx: usize) -> Self {
+        Self::ALL[idx % 4]
+    }
+
+    /// Turns 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::from_idx(self.idx() + 1)
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_idx(self.idx() + 3)
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::from_idx(self.idx() + 2)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir::Left | Dir::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        }
+    }
+
+    pub fn from_delta(delta: Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta() == delta)
+    }
+
+    /// The position one step away from `pos` in this direction.
+    pub fn step(self, (x, y): Point) -> Point {
+        let (dx, dy) = self.delta();
+        (x + dx, y + dy)
+    }
+
+    /// Parses any of the usual spellings: `^>v<`, `URDL`, or `NESW`.
+    pub fn from_byte(c: u8) -> Option<Self> {
+        Some(match c {
+            b'^' | b'U' | b'N' => Dir::Up,
+            b'>' | b'R' | b'E' => Dir::Right,
+            b'v' | b'D' | b'S' => Dir::Down,
+            b'<' | b'L' | b'W' => Dir::Left,
+            _ => return None,
+        })
+    }
+
+    /// The arrow pointing this way, one of `^>v<`.
+    pub fn arrow(self) -> u8 {
+        b"^>v<"[self.idx()]
+    }
+}
+
+/// One of the 8 orthogonal or diagonal directions, ordered clockwise starting from [`Dir8::Up`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn idx(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_idx(idx: usize) -> Self {
+        Self::ALL[idx % 8]
+    }
+
+    /// Turns 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::from_idx(self.idx() + 1)
+    }
+
+    /// Turns 45° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_idx(self.idx() + 7)
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::from_idx(self.idx() + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.idx() & 1 == 1
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn from_delta(delta: Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta() == delta)
+    }
+
+    /// The position one step away from `pos` in this direction.
+    pub fn step(self, (x, y): Point) -> Point {
+        let (dx, dy) = self.delta();
+        (x + dx, y + dy)
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        Dir8::from_idx(dir.idx() * 2)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+impl FromStr for Dir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[c] => Dir::from_byte(c),
+            _ => None,
+        }
+        .ok_or_else(|| ParseDirError(s.to_string()))
+    }
+}
+
+impl<T> Index<Dir> for [T; 4] {
+    type Output = T;
+
+    fn index(&self, dir: Dir) -> &T {
+        &self[dir.idx()]
+    }
+}
+
+impl<T> IndexMut<Dir> for [T; 4] {
+    fn index_mut(&mut self, dir: Dir) -> &mut T {
+        &mut self[dir.idx()]
+    }
+}
+
+impl<T> Index<Dir8> for [T; 8] {
+    type Output = T;
+
+    fn index(&self, dir: Dir8) -> &T {
+        &self[dir.idx()]
+    }
+}
+
+impl<T> IndexMut<Dir8> for [T; 8] {
+    fn index_mut(&mut self, dir: Dir8) -> &mut T {
+        &mut self[dir.idx()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.turn_around());
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.turn_right().delta(), (-dy, dx));
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_around().turn_around(), dir);
+            assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+        assert_eq!(Dir8::from(Dir::Left), Dir8::Left);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            "^>v<".bytes().map(Dir::from_byte).collect::<Vec<_>>(),
+            Dir::ALL.map(Some)
+        );
+        assert_eq!(
+            "URDL".bytes().map(Dir::from_byte).collect::<Vec<_>>(),
+            Dir::ALL.map(Some)
+        );
+        assert_eq!(
+            "NESW".bytes().map(Dir::from_byte).collect::<Vec<_>>(),
+            Dir::ALL.map(Some)
+        );
+        for dir in Dir::ALL {
+            assert_eq!(Dir::from_byte(dir.arrow()), Some(dir));
+        }
+        assert_eq!("L".parse(), Ok(Dir::Left));
+        assert!("LL".parse::<Dir>().is_err());
+    }
+
+    #[test]
+    fn array_index() {
+        let mut seen = [false; 4];
+        seen[Dir::Down] = true;
+        assert_eq!(seen, [false, false, true, false]);
+        assert_eq!(Dir::from_delta((0, 1)), Some(Dir::Down));
+        assert_eq!(Dir8::from_delta((-1, -1)), Some(Dir8::UpLeft));
+    }
+}
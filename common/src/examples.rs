@@ -0,0 +1,84 @@
+//! Example inputs read back out of a day's source, e.g. to seed a fuzzing corpus with them.
+//!
+//! Examples are the `static NAME: &str` items in the days' tests,
+//! written either as raw strings or as plain, single line, ones.
+
+/// Names and contents of the string statics in `source`, in order.
+///
+/// Statics which aren't initialized with a string literal, like the ones using `include_str!`,
+/// are skipped.
+pub fn from_source(source: &str) -> Vec<(&str, String)> {
+    let mut res = Vec::new();
+    let mut rest = source;
+
+    while let Some(idx) = rest.find("static ") {
+        rest = &rest[idx + "static ".len()..];
+        let Some((name, value)) = rest.split_once(": &str =") else {
+            break;
+        };
+        let is_ident = |b: u8| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_';
+        if name.is_empty() || !name.bytes().all(is_ident) {
+            continue;
+        }
+
+        let value = value.trim_start();
+        let example = if let Some(raw) = value.strip_prefix("r#\"") {
+            raw.split_once("\"#;").map(|(s, _)| s.to_string())
+        } else if let Some(s) = value.strip_prefix('"') {
+            unescape(s)
+        } else {
+            None
+        };
+        res.extend(example.map(|example| (name, example)));
+    }
+
+    res
+}
+
+/// Contents of a string literal up to its closing quote.
+fn unescape(s: &str) -> Option<String> {
+    let mut res = String::new();
+    let mut chars = s.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some(res),
+            '\\' => res.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c => c,
+            }),
+            c => res.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statics() {
+        let source = r##"
+static INPUT: &str = include_str!("../inputs/day01/input");
+
+mod tests {
+    static EX_INPUT_1: &str = r#"
+1 2
+3 4
+"#;
+
+    static EX_INPUT_2: &str =
+        "a \"quoted\"\nline";
+
+    const EX_INPUT_2_SOL: u64 = 4;
+}
+"##;
+        assert_eq!(
+            from_source(source),
+            [
+                ("EX_INPUT_1", "\n1 2\n3 4\n".to_string()),
+                ("EX_INPUT_2", "a \"quoted\"\nline".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Indexed adjacency-list graph with labeled nodes and edges,
+//! plus a writer for Graphviz's DOT format.
+//!
+//! Nodes are addressed by dense [`NodeId`]s handed out in insertion order,
+//! so per-node state can live in plain `Vec`s indexed by id.
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+pub type NodeId = usize;
+pub type EdgeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge<E> {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub label: E,
+}
+
+impl<E> Edge<E> {
+    /// Returns the endpoint on the other side of `node`.
+    pub fn other(&self, node: NodeId) -> NodeId {
+        if self.from == node {
+            self.to
+        } else {
+            self.from
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    directed: bool,
+    nodes: Vec<N>,
+    index: HashMap<N, NodeId>,
+    edges: Vec<Edge<E>>,
+    outgoing: Vec<Vec<EdgeId>>,
+    incoming: Vec<Vec<EdgeId>>,
+}
+
+impl<N, E> Graph<N, E> {
+    fn new(directed: bool) -> Self {
+        Graph {
+            directed,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn edge(&self, id: EdgeId) -> &Edge<E> {
+        &self.edges[id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (EdgeId, &Edge<E>)> {
+        self.edges.iter().enumerate()
+    }
+
+    /// Adds an edge between two existing nodes.
+    /// In an undirected graph the edge is reachable from both of its endpoints.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, label: E) -> EdgeId {
+        let id = self.edges.len();
+        self.edges.push(Edge { from, to, label });
+        self.outgoing[from].push(id);
+        if self.directed {
+            self.incoming[to].push(id);
+        } else if from != to {
+            self.outgoing[to].push(id);
+        }
+        id
+    }
+
+    /// Ids of the edges leaving `node`, or touching it if the graph is undirected.
+    pub fn edges_from(&self, node: NodeId) -> impl Iterator<Item = EdgeId> + '_ {
+        self.outgoing[node].iter().copied()
+    }
+
+    /// Ids of the edges entering `node`, or touching it if the graph is undirected.
+    pub fn edges_to(&self, node: NodeId) -> impl Iterator<Item = EdgeId> + '_ {
+        let edges = if self.directed {
+            &self.incoming[node]
+        } else {
+            &self.outgoing[node]
+        };
+        edges.iter().copied()
+    }
+
+    /// Nodes reachable from `node` over a single edge.
+    pub fn neighbors(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        self.edges_from(node).map(move |id| {
+            let edge = &self.edges[id];
+            (edge.other(node), &edge.label)
+        })
+    }
+
+    /// Nodes from which `node` is reachable over a single edge.
+    pub fn predecessors(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        self.edges_to(node).map(move |id| {
+            let edge = &self.edges[id];
+            (edge.other(node), &edge.label)
+        })
+    }
+
+    pub fn degree(&self, node: NodeId) -> usize {
+        self.outgoing[node].len()
+    }
+
+    pub fn dot(&self) -> Dot<'_, N, E> {
+        Dot {
+            graph: self,
+            node_label: None,
+            node_attrs: None,
+            edge_attrs: None,
+        }
+    }
+}
+
+impl<N: Hash + Eq + Clone, E> Graph<N, E> {
+    /// Returns the id of the node labeled `label`, inserting it first if it doesn't exist yet.
+    pub fn add_node(&mut self, label: N) -> NodeId {
+        if let Some(&id) = self.index.get(&label) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.index.insert(label.clone(), id);
+        self.nodes.push(label);
+        self.outgoing.push(Vec::new());
+        if self.directed {
+            self.incoming.push(Vec::new());
+        }
+        id
+    }
+
+    pub fn find<Q>(&self, label: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(label).copied()
+    }
+
+    /// Adds an edge between the nodes labeled `from` and `to`, inserting them if needed.
+    pub fn connect(&mut self, from: N, to: N, label: E) -> EdgeId {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge(from, to, label)
+    }
+}
+
+type AttrFn<'g, T> = Box<dyn Fn(usize, &T) -> String + 'g>;
+
+/// DOT rendering of a [`Graph`], created with [`Graph::dot`].
+///
+/// Nodes are labeled with their `Display` representation unless [`Dot::node_label`] says otherwise.
+/// Extra attributes, e.g. `shape=box` or `color=red`, can be attached to nodes and edges
+/// with [`Dot::node_attrs`] and [`Dot::edge_attrs`].
+pub struct Dot<'g, N, E> {
+    graph: &'g Graph<N, E>,
+    node_label: Option<AttrFn<'g, N>>,
+    node_attrs: Option<AttrFn<'g, N>>,
+    edge_attrs: Option<AttrFn<'g, Edge<E>>>,
+}
+
+impl<'g, N, E> Dot<'g, N, E> {
+    pub fn node_label(mut self, f: impl Fn(NodeId, &N) -> String + 'g) -> Self {
+        self.node_label = Some(Box::new(f));
+        self
+    }
+
+    pub fn node_attrs(mut self, f: impl Fn(NodeId, &N) -> String + 'g) -> Self {
+        self.node_attrs = Some(Box::new(f));
+        self
+    }
+
+    pub fn edge_attrs(mut self, f: impl Fn(EdgeId, &Edge<E>) -> String + 'g) -> Self {
+        self.edge_attrs = Some(Box::new(f));
+        self
+    }
+}
+
+fn write_attrs(f: &mut fmt::Formatter<'_>, label: Option<&str>, extra: &str) -> fmt::Result {
+    if label.is_none() && extra.is_empty() {
+        return Ok(());
+    }
+    f.write_str(" [")?;
+    if let Some(label) = label {
+        f.write_str("label=\"")?;
+        for c in label.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        f.write_str("\"")?;
+        if !extra.is_empty() {
+            f.write_str(", ")?;
+        }
+    }
+    write!(f, "{extra}]")
+}
+
+impl<N: Display, E> Display for Dot<'_, N, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, arrow) = if self.graph.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{kind} {{")?;
+        for (id, node) in self.graph.nodes() {
+            let label = match &self.node_label {
+                Some(l) => l(id, node),
+                None => node.to_string(),
+            };
+            let extra = self.node_attrs.as_ref().map(|a| a(id, node));
+            write!(f, "    n{id}")?;
+            write_attrs(f, Some(&label), extra.as_deref().unwrap_or(""))?;
+            writeln!(f)?;
+        }
+        for (id, edge) in self.graph.edges() {
+            let extra = self.edge_attrs.as_ref().map(|a| a(id, edge));
+            write!(f, "    n{} {arrow} n{}", edge.from, edge.to)?;
+            write_attrs(f, None, extra.as_deref().unwrap_or(""))?;
+            writeln!(f)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Whether the current binary was invoked with `--dot`,
+/// in which case days that have a graph to show print it in DOT format to stderr.
+pub fn dot_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--dot")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected_neighbors() {
+        let mut g = Graph::<&str>::undirected();
+        g.connect("a", "b", ());
+        g.connect("b", "c", ());
+        let b = g.find("b").unwrap();
+        let mut n: Vec<_> = g.neighbors(b).map(|(id, _)| *g.node(id)).collect();
+        n.sort();
+        assert_eq!(n, ["a", "c"]);
+        assert_eq!(g.predecessors(b).count(), 2);
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+    }
+
+    #[test]
+    fn directed_predecessors() {
+        let mut g = Graph::<&str, u32>::directed();
+        g.connect("a", "c", 1);
+        g.connect("b", "c", 2);
+        let c = g.find("c").unwrap();
+        assert_eq!(g.neighbors(c).count(), 0);
+        let preds: Vec<_> = g.predecessors(c).map(|(id, &w)| (*g.node(id), w)).collect();
+        assert_eq!(preds, [("a", 1), ("b", 2)]);
+    }
+
+    #[test]
+    fn dot_output() {
+        let mut g = Graph::<&str, &str>::directed();
+        g.connect("x\"1", "z", "xor");
+        let dot = g
+            .dot()
+            .node_label(|id, n| format!("{n}{id}"))
+            .node_attrs(|_, &n| {
+                if n == "z" {
+                    "shape=box".into()
+                } else {
+                    String::new()
+                }
+            })
+            .edge_attrs(|_, e| format!("label={}", e.label))
+            .to_string();
+        assert_eq!(
+            dot,
+            "digraph {\n    n0 [label=\"x\\\"10\"]\n    n1 [label=\"z1\", shape=box]\n    n0 -> n1 [label=xor]\n}\n"
+        );
+    }
+}
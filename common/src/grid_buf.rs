@@ -0,0 +1,310 @@
+//! Owned, mutable counterpart to [`aoc_lib::grid::Grid`].
+//!
+//! `Grid` borrows the input and can't be changed, which is fine for lookups
+//! but not for simulations that move things around.
+//! [`GridBuf`] stores its cells row by row in a single `Vec` and is addressed with the same [`Point`]s.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use aoc_lib::grid::{Grid, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridBuf<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> GridBuf<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        GridBuf {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid out of its rows. Returns `None` if they aren't all the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Option<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+            if *width.get_or_insert(cells.len() - len) != cells.len() - len {
+                return None;
+            }
+            height += 1;
+        }
+        Some(GridBuf {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> i64 {
+        self.width as i64
+    }
+
+    pub fn height(&self) -> i64 {
+        self.height as i64
+    }
+
+    pub fn is_valid_pos(&self, (x, y): Point) -> bool {
+        (0..self.width()).contains(&x) && (0..self.height()).contains(&y)
+    }
+
+    fn idx(&self, pos: Point) -> Option<usize> {
+        self.is_valid_pos(pos)
+            .then(|| pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.idx(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.idx(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Overwrites the cell at `pos`, returning the previous value if `pos` was inside the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// All cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (((idx % width) as i64, (idx / width) as i64), cell))
+    }
+
+    /// Position of the first cell (row by row) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|&(_, cell)| cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: i64) -> &[T] {
+        let start = y as usize * self.width;
+        &self.cells[start..start + self.width]
+    }
+
+    pub fn row_mut(&mut self, y: i64) -> &mut [T] {
+        let start = y as usize * self.width;
+        &mut self.cells[start..start + self.width]
+    }
+
+    pub fn column(&self, x: i64) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!((0..self.width()).contains(&x), "column {x} out of bounds");
+        self.cells[x as usize..].iter().step_by(self.width)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    pub fn iter_columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width()).map(|x| self.column(x))
+    }
+
+    /// Valid positions directly above, below, left, and right of `pos`.
+    pub fn orthogonal_pos(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+            .into_iter()
+            .filter(|&p| self.is_valid_pos(p))
+    }
+
+    /// Inserts `row` before row `y`, shifting everything below it down.
+    ///
+    /// # Panics
+    ///
+    /// If `row` doesn't have exactly [`width`](Self::width) cells.
+    pub fn insert_row(&mut self, y: i64, row: impl IntoIterator<Item = T>) {
+        let start = y as usize * self.width;
+        let len = self.cells.len();
+        self.cells.splice(start..start, row);
+        assert_eq!(
+            self.cells.len() - len,
+            self.width,
+            "row has the wrong length"
+        );
+        self.height += 1;
+    }
+
+    /// Inserts `column` before column `x`, shifting everything to its right over.
+    ///
+    /// # Panics
+    ///
+    /// If `column` doesn't have exactly [`height`](Self::height) cells.
+    pub fn insert_column(&mut self, x: i64, column: impl IntoIterator<Item = T>) {
+        let x = x as usize;
+        let mut column = column.into_iter();
+        let mut cells = std::mem::take(&mut self.cells).into_iter();
+        let mut new_cells = Vec::with_capacity((self.width + 1) * self.height);
+        for _ in 0..self.height {
+            new_cells.extend(cells.by_ref().take(x));
+            new_cells.push(column.next().expect("column is too short"));
+            new_cells.extend(cells.by_ref().take(self.width - x));
+        }
+        assert!(column.next().is_none(), "column is too long");
+        self.cells = new_cells;
+        self.width += 1;
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.iter_rows() {
+            res.extend(row.iter().map(&mut f));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T: Clone> GridBuf<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        GridBuf {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height();
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, h - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90° counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width();
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(w - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width();
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(w - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height();
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, h - 1 - y)].clone()
+        })
+    }
+}
+
+impl GridBuf<u8> {
+    /// Parses a block of text, ignoring surrounding whitespace.
+    /// Returns `None` if the lines aren't all the same length.
+    pub fn for_str(s: &str) -> Option<Self> {
+        Self::from_rows(s.trim().lines().map(str::bytes))
+    }
+}
+
+impl From<Grid<'_>> for GridBuf<u8> {
+    fn from(grid: Grid<'_>) -> Self {
+        Self::from_fn(grid.width() as usize, grid.height() as usize, |p| grid[p])
+    }
+}
+
+impl<T> Index<Point> for GridBuf<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for GridBuf<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl fmt::Display for GridBuf<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c as char))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "ab\ncd\nef\n";
+
+    #[test]
+    fn parse_and_render() {
+        let grid = GridBuf::for_str(GRID).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 2)], b'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), GRID);
+        assert!(GridBuf::for_str("ab\nc").is_none());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = GridBuf::for_str(GRID).unwrap();
+        assert_eq!(grid.row(1), b"cd");
+        assert_eq!(grid.column(1).rev().copied().collect::<Vec<_>>(), b"fdb");
+        assert_eq!(grid.iter_columns().count(), 2);
+        assert_eq!(grid.find(&b'e'), Some((0, 2)));
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = GridBuf::for_str(GRID).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "bdf\nace\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn insertion() {
+        let mut grid = GridBuf::for_str(GRID).unwrap();
+        grid.set((0, 0), b'A');
+        grid.insert_row(1, *b"xy");
+        grid.insert_column(2, *b"1234");
+        assert_eq!(grid.to_string(), "Ab1\nxy2\ncd3\nef4\n");
+    }
+}
@@ -0,0 +1,285 @@
+//! Dense replacements for `HashSet<Point>` and `HashMap<Point, T>` on bounded grids.
+//!
+//! Every cell gets a fixed slot (or [`GridKey::PER_CELL`] slots when the key also carries
+//! a [`Dir`]), so lookups are plain indexing instead of hashing.
+
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use aoc_lib::grid::{Grid, Point};
+
+use crate::dir::Dir;
+
+/// Something that can be stored in a [`GridSet`] or [`GridMap`]:
+/// a position plus, optionally, a bit of extra state per cell.
+pub trait GridKey: Copy {
+    /// How many distinct keys share a single cell.
+    const PER_CELL: usize;
+
+    fn pos(self) -> Point;
+
+    /// Which of the cell's `PER_CELL` slots this key occupies.
+    fn slot(self) -> usize;
+
+    fn from_parts(pos: Point, slot: usize) -> Self;
+}
+
+impl GridKey for Point {
+    const PER_CELL: usize = 1;
+
+    fn pos(self) -> Point {
+        self
+    }
+
+    fn slot(self) -> usize {
+        0
+    }
+
+    fn from_parts(pos: Point, _: usize) -> Self {
+        pos
+    }
+}
+
+impl GridKey for (Point, Dir) {
+    const PER_CELL: usize = 4;
+
+    fn pos(self) -> Point {
+        self.0
+    }
+
+    fn slot(self) -> usize {
+        self.1.idx()
+    }
+
+    fn from_parts(pos: Point, slot: usize) -> Self {
+        (pos, Dir::from_idx(slot))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    width: i64,
+    height: i64,
+}
+
+impl Bounds {
+    fn slots<K: GridKey>(self) -> usize {
+        (self.width * self.height) as usize * K::PER_CELL
+    }
+
+    fn idx<K: GridKey>(self, key: K) -> Option<usize> {
+        let (x, y) = key.pos();
+        ((0..self.width).contains(&x) && (0..self.height).contains(&y))
+            .then(|| (y * self.width + x) as usize * K::PER_CELL + key.slot())
+    }
+
+    fn key<K: GridKey>(self, idx: usize) -> K {
+        let cell = (idx / K::PER_CELL) as i64;
+        K::from_parts((cell % self.width, cell / self.width), idx % K::PER_CELL)
+    }
+}
+
+/// Bitset over all the keys of a `width` x `height` grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridSet<K = Point> {
+    bounds: Bounds,
+    bits: Vec<u64>,
+    len: usize,
+    _key: PhantomData<K>,
+}
+
+impl<K: GridKey> GridSet<K> {
+    pub fn new(width: i64, height: i64) -> Self {
+        let bounds = Bounds { width, height };
+        GridSet {
+            bounds,
+            bits: vec![0; bounds.slots::<K>().div_ceil(64)],
+            len: 0,
+            _key: PhantomData,
+        }
+    }
+
+    pub fn for_grid(grid: &Grid<'_>) -> Self {
+        Self::new(grid.width(), grid.height())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Keys outside of the grid are never contained in the set.
+    pub fn contains(&self, key: K) -> bool {
+        self.bounds
+            .idx(key)
+            .is_some_and(|idx| self.bits[idx / 64] & (1 << (idx % 64)) != 0)
+    }
+
+    /// Returns whether the key was newly inserted, like [`HashSet::insert`](std::collections::HashSet::insert).
+    ///
+    /// # Panics
+    ///
+    /// If the key's position is outside of the grid.
+    pub fn insert(&mut self, key: K) -> bool {
+        let idx = self
+            .bounds
+            .idx(key)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", key.pos()));
+        let word = &mut self.bits[idx / 64];
+        let mask = 1 << (idx % 64);
+        let new = *word & mask == 0;
+        *word |= mask;
+        self.len += new as usize;
+        new
+    }
+
+    /// Returns whether the key was present.
+    pub fn remove(&mut self, key: K) -> bool {
+        let Some(idx) = self.bounds.idx(key) else {
+            return false;
+        };
+        let word = &mut self.bits[idx / 64];
+        let mask = 1 << (idx % 64);
+        let present = *word & mask != 0;
+        *word &= !mask;
+        self.len -= present as usize;
+        present
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+        self.len = 0;
+    }
+
+    /// Keys in the set, ordered row by row.
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        self.bits.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(self.bounds.key(i * 64 + bit))
+            })
+        })
+    }
+}
+
+impl<K: GridKey> Extend<K> for GridSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+/// Dense map from every key of a `width` x `height` grid to a `T`.
+///
+/// All slots start out with the same value, so use `GridMap<Option<T>>`
+/// where a `HashMap` would have told missing keys apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridMap<T, K = Point> {
+    bounds: Bounds,
+    cells: Vec<T>,
+    _key: PhantomData<K>,
+}
+
+impl<T: Clone, K: GridKey> GridMap<T, K> {
+    pub fn new(width: i64, height: i64, value: T) -> Self {
+        let bounds = Bounds { width, height };
+        GridMap {
+            bounds,
+            cells: vec![value; bounds.slots::<K>()],
+            _key: PhantomData,
+        }
+    }
+
+    pub fn for_grid(grid: &Grid<'_>, value: T) -> Self {
+        Self::new(grid.width(), grid.height(), value)
+    }
+}
+
+impl<T, K: GridKey> GridMap<T, K> {
+    pub fn get(&self, key: K) -> Option<&T> {
+        self.bounds.idx(key).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, key: K) -> Option<&mut T> {
+        self.bounds.idx(key).map(|idx| &mut self.cells[idx])
+    }
+
+    /// All the slots together with their keys, ordered row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (K, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| (self.bounds.key(idx), value))
+    }
+}
+
+impl<T, K: GridKey> Index<K> for GridMap<T, K> {
+    type Output = T;
+
+    fn index(&self, key: K) -> &T {
+        self.get(key)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", key.pos()))
+    }
+}
+
+impl<T, K: GridKey> IndexMut<K> for GridMap<T, K> {
+    fn index_mut(&mut self, key: K) -> &mut T {
+        self.get_mut(key)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", key.pos()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set() {
+        let mut set = GridSet::new(10, 7);
+        assert!(set.insert((3, 4)));
+        assert!(!set.insert((3, 4)));
+        assert!(set.insert((9, 6)));
+        assert!(set.insert((0, 0)));
+        assert!(!set.contains((10, 0)));
+        assert!(!set.contains((-1, 3)));
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [(0, 0), (3, 4), (9, 6)]);
+        assert!(set.remove((3, 4)));
+        assert!(!set.remove((3, 4)));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn set_with_dirs() {
+        let mut set = GridSet::<(Point, Dir)>::new(5, 5);
+        set.insert(((1, 1), Dir::Left));
+        set.insert(((1, 1), Dir::Up));
+        assert!(set.contains(((1, 1), Dir::Left)));
+        assert!(!set.contains(((1, 1), Dir::Right)));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [((1, 1), Dir::Up), ((1, 1), Dir::Left)]
+        );
+    }
+
+    #[test]
+    fn map() {
+        let mut map = GridMap::<Option<u32>>::new(4, 3, None);
+        map[(2, 1)] = Some(5);
+        assert_eq!(map.get((2, 1)), Some(&Some(5)));
+        assert_eq!(map.get((4, 1)), None);
+        let set: Vec<_> = map.iter().filter(|(_, v)| v.is_some()).collect();
+        assert_eq!(set, [((2, 1), &Some(5))]);
+    }
+}
@@ -0,0 +1,168 @@
+//! Interning of names, like the nodes of the graph puzzles, into dense [`Id`]s,
+//! so per-name state can live in plain `Vec`s indexed by id instead of maps keyed on strings.
+//!
+//! Most puzzles name things with up to three ASCII letters or digits, like `AAA` or `z05`.
+//! Those are packed into an integer indexing a table of ids,
+//! so looking them up doesn't hash or compare strings.
+
+use std::collections::HashMap;
+
+/// Ids are handed out in interning order, starting from 0.
+pub type Id = u32;
+
+/// Every name made of up to three ASCII letters or digits packs below this.
+const PACKED_NAMES: usize = 63 * 63 * 63;
+
+#[derive(Debug, Clone, Default)]
+pub struct Interner<'a> {
+    names: Vec<&'a str>,
+    /// Id + 1 of every packed name, 0 for the ones not interned yet.
+    /// Only allocated once the first short name gets interned.
+    packed: Vec<Id>,
+    long: HashMap<&'a str, Id>,
+}
+
+/// Packs a name of up to three ASCII letters or digits into a unique integer below [`PACKED_NAMES`].
+fn pack(name: &str) -> Option<usize> {
+    if name.len() > 3 {
+        return None;
+    }
+    name.bytes().try_fold(0, |res, b| {
+        // 0 is left out of the digits, so names of different lengths don't collide
+        let digit = match b {
+            b'0'..=b'9' => b - b'0' + 1,
+            b'a'..=b'z' => b - b'a' + 11,
+            b'A'..=b'Z' => b - b'A' + 37,
+            _ => return None,
+        };
+        Some(res * 63 + digit as usize)
+    })
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The id of `name`, handing out the next one if it hasn't been interned yet.
+    pub fn intern(&mut self, name: &'a str) -> Id {
+        let next = self.names.len() as Id;
+        let id = match pack(name) {
+            Some(packed) => {
+                if self.packed.is_empty() {
+                    self.packed = vec![0; PACKED_NAMES];
+                }
+                let slot = &mut self.packed[packed];
+                if *slot == 0 {
+                    *slot = next + 1;
+                }
+                *slot - 1
+            }
+            None => *self.long.entry(name).or_insert(next),
+        };
+        if id == next {
+            self.names.push(name);
+        }
+        id
+    }
+
+    /// The id of `name`, if it has been interned.
+    pub fn get(&self, name: &str) -> Option<Id> {
+        match pack(name) {
+            Some(packed) => self
+                .packed
+                .get(packed)
+                .and_then(|&slot| slot.checked_sub(1)),
+            None => self.long.get(name).copied(),
+        }
+    }
+
+    /// The name interned as `id`.
+    pub fn name(&self, id: Id) -> &'a str {
+        self.names[id as usize]
+    }
+
+    /// Every id and its name, in interning order.
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &'a str)> + '_ {
+        (0..).zip(self.names.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut names = Interner::new();
+        let words = [
+            "AAA",
+            "z05",
+            "a",
+            "aa",
+            "0a",
+            "",
+            "broadcaster",
+            "a-b",
+            "AAA",
+            "aa",
+        ];
+        let ids = words.map(|word| names.intern(word));
+
+        assert_eq!(ids, [0, 1, 2, 3, 4, 5, 6, 7, 0, 3]);
+        assert_eq!(names.len(), 8);
+        for (word, id) in words.into_iter().zip(ids) {
+            assert_eq!(names.name(id), word);
+            assert_eq!(names.get(word), Some(id));
+        }
+        assert_eq!(names.iter().nth(6), Some((6, "broadcaster")));
+    }
+
+    #[test]
+    fn missing() {
+        let mut names = Interner::new();
+        assert_eq!(names.get("AAA"), None);
+        assert_eq!(names.get("broadcaster"), None);
+
+        names.intern("AAA");
+        assert_eq!(names.get("AA"), None);
+        assert_eq!(names.get("AAB"), None);
+    }
+
+    #[test]
+    fn packing_is_unique() {
+        let chars: Vec<_> = (b'0'..=b'9')
+            .chain(b'a'..=b'z')
+            .chain(b'A'..=b'Z')
+            .map(|b| (b as char).to_string())
+            .collect();
+        let mut names = vec![String::new()];
+        for len in 1..=3 {
+            let shorter: Vec<_> = names
+                .iter()
+                .filter(|name| name.len() == len - 1)
+                .cloned()
+                .collect();
+            for name in shorter {
+                names.extend(chars.iter().map(|c| format!("{name}{c}")));
+            }
+        }
+
+        let mut seen = vec![false; PACKED_NAMES];
+        for name in &names {
+            let packed = pack(name).unwrap();
+            assert!(!seen[packed], "{name} collides");
+            seen[packed] = true;
+        }
+        assert_eq!(pack("AAAA"), None);
+        assert_eq!(pack("a-"), None);
+    }
+}
@@ -0,0 +1,22 @@
+//! Helpers shared between the yearly solution crates
+//! which don't (yet) have a home in `aoc-lib`.
+
+pub mod answer;
+pub mod checked;
+pub mod debugger;
+pub mod dir;
+pub mod examples;
+pub mod graph;
+pub mod grid_buf;
+pub mod grid_map;
+pub mod intern;
+pub mod maze;
+pub mod memo;
+pub mod parse;
+pub mod pipes;
+pub mod report;
+pub mod rng;
+pub mod run;
+pub mod search;
+pub mod sparse_grid;
+pub mod vec3;
@@ -0,0 +1,197 @@
+//! Random mazes in the input formats of the maze days, to test them on more than the puzzle examples.
+//!
+//! Every maze is carved into solid wall (`#`) as a perfect maze, where the cells at odd coordinates
+//! are joined by exactly one path, which is then pruned or opened up into the shape each day needs.
+//! Mazes are generated with an [`Rng`], so a seed is all it takes to get the same one again.
+//!
+//! Widths and heights have to be odd, like the real inputs, so the cells line up with the borders.
+//! To write a maze out, e.g. one 10 times the size of a real input:
+//! `cargo run --release --example maze -- racetrack 1411 1411 42 > input`.
+
+use aoc_lib::grid::Point;
+
+use crate::{dir::Dir, grid_buf::GridBuf, rng::Rng, search::bfs};
+
+/// The 2024 day 20 racetrack: a single track from `S` to `E`, only one tile wide,
+/// winding through the whole maze, with no other open tiles.
+pub fn racetrack(width: i64, height: i64, rng: &mut Rng) -> String {
+    let maze = carve(width, height, rng);
+    let start = (1, 1);
+    let paths = bfs(start, |&pos| open_neighbors(&maze, pos), |_| false);
+    // ties are broken on position, so the end doesn't depend on the map's iteration order
+    let (&end, _) = paths
+        .dist()
+        .iter()
+        .max_by_key(|&(&pos, &dist)| (dist, pos))
+        .unwrap();
+
+    let mut track = GridBuf::new(width as usize, height as usize, b'#');
+    for pos in paths.path_to(&end).unwrap() {
+        track[pos] = b'.';
+    }
+    track[start] = b'S';
+    track[end] = b'E';
+    track.to_string()
+}
+
+/// The 2024 day 16 reindeer maze: `S` in the bottom left corner and `E` in the top right one.
+///
+/// `loops` is the chance of knocking down each wall left between two cells,
+/// 0 leaving exactly one path between any two tiles and 1 leaving a grid of pillars.
+pub fn branching(width: i64, height: i64, loops: f64, rng: &mut Rng) -> String {
+    let mut maze = carve(width, height, rng);
+    knock_down_walls(&mut maze, loops, rng);
+    maze[(1, height - 2)] = b'S';
+    maze[(width - 2, 1)] = b'E';
+    maze.to_string()
+}
+
+/// The 2023 day 23 hiking trails: entered through the gap in the top row and left through the one
+/// in the bottom row, with `loops` like in [`branching`].
+///
+/// Every path leaving a junction starts with a slope pointing away from the entrance,
+/// so the trails are never walked around in circles when going down the slopes.
+pub fn slopes(width: i64, height: i64, loops: f64, rng: &mut Rng) -> String {
+    let mut maze = carve(width, height, rng);
+    knock_down_walls(&mut maze, loops, rng);
+    let start = (1, 0);
+    maze[start] = b'.';
+    maze[(width - 2, height - 1)] = b'.';
+
+    let paths = bfs(start, |&pos| open_neighbors(&maze, pos), |_| false);
+    let dist = |pos| paths.cost(&pos).unwrap();
+    let junctions: Vec<_> = maze
+        .iter()
+        .filter(|&(pos, &c)| c == b'.' && open_neighbors(&maze, pos).count() > 2)
+        .map(|(pos, _)| pos)
+        .collect();
+    for junction in junctions {
+        for dir in Dir::ALL {
+            let path = dir.step(junction);
+            if maze[path] != b'.' || is_border(&maze, path) {
+                continue;
+            }
+            // paths between two cells are never on the border, and neither are the cells they join
+            let next = dir.step(path);
+            let downhill = if dist(next) > dist(junction) {
+                dir
+            } else {
+                dir.turn_around()
+            };
+            maze[path] = downhill.arrow();
+        }
+    }
+    maze.to_string()
+}
+
+fn is_border(maze: &GridBuf<u8>, (x, y): Point) -> bool {
+    x == 0 || y == 0 || x == maze.width() - 1 || y == maze.height() - 1
+}
+
+fn open_neighbors(maze: &GridBuf<u8>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+    maze.orthogonal_pos(pos).filter(|&pos| maze[pos] != b'#')
+}
+
+/// A perfect maze, carved by a random depth-first walk from the top left cell.
+fn carve(width: i64, height: i64, rng: &mut Rng) -> GridBuf<u8> {
+    assert!(
+        width >= 3 && height >= 3 && width % 2 == 1 && height % 2 == 1,
+        "mazes need odd sizes of at least 3, not {width}x{height}"
+    );
+    let mut maze = GridBuf::new(width as usize, height as usize, b'#');
+    let start = (1, 1);
+    maze[start] = b'.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited: Vec<_> = Dir::ALL
+            .into_iter()
+            .filter(|dir| {
+                let next = dir.step(dir.step(cell));
+                maze.get(next).is_some_and(|&c| c == b'#') && !is_border(&maze, next)
+            })
+            .collect();
+        let Some(&dir) = rng.pick(&unvisited) else {
+            stack.pop();
+            continue;
+        };
+        let next = dir.step(dir.step(cell));
+        maze[dir.step(cell)] = b'.';
+        maze[next] = b'.';
+        stack.push(next);
+    }
+    maze
+}
+
+/// Opens up each wall between two cells with chance `p`, adding loops to the maze.
+fn knock_down_walls(maze: &mut GridBuf<u8>, p: f64, rng: &mut Rng) {
+    for y in 1..maze.height() - 1 {
+        for x in 1..maze.width() - 1 {
+            // walls between two cells have one odd and one even coordinate
+            if (x + y) % 2 == 1 && maze[(x, y)] == b'#' && rng.chance(p) {
+                maze[(x, y)] = b'.';
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(maze: &str) -> GridBuf<u8> {
+        GridBuf::for_str(maze).unwrap()
+    }
+
+    fn count(maze: &GridBuf<u8>, c: u8) -> usize {
+        maze.iter().filter(|&(_, &cell)| cell == c).count()
+    }
+
+    #[test]
+    fn seeded() {
+        let maze = |seed| branching(21, 11, 0.1, &mut Rng::new(seed));
+        assert_eq!(maze(1), maze(1));
+        assert_ne!(maze(1), maze(2));
+    }
+
+    #[test]
+    fn racetrack_is_single_track() {
+        for seed in 0..20 {
+            let maze = parse(&racetrack(31, 21, &mut Rng::new(seed)));
+            assert_eq!((maze.width(), maze.height()), (31, 21));
+            assert_eq!((count(&maze, b'S'), count(&maze, b'E')), (1, 1));
+            for (pos, &c) in maze.iter() {
+                let ends = if matches!(c, b'S' | b'E') { 1 } else { 2 };
+                if c != b'#' {
+                    assert_eq!(open_neighbors(&maze, pos).count(), ends, "seed {seed}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn loops() {
+        let walls = |loops| count(&parse(&branching(41, 41, loops, &mut Rng::new(0))), b'#');
+        // a perfect maze on 20x20 cells has 20*20 - 1 paths between them
+        assert_eq!(walls(0.0), 41 * 41 - 20 * 20 - (20 * 20 - 1));
+        assert!(walls(0.2) < walls(0.0));
+        // only the border and the pillars between cells are left
+        assert_eq!(walls(1.0), 41 * 41 - 39 * 39 + 19 * 19);
+    }
+
+    #[test]
+    fn slopes_point_downhill() {
+        for seed in 0..20 {
+            let maze = parse(&slopes(21, 21, 0.2, &mut Rng::new(seed)));
+            assert_eq!(maze[(1, 0)], b'.');
+            assert_eq!(maze[(19, 20)], b'.');
+            assert!(count(&maze, b'>') + count(&maze, b'v') > 0, "seed {seed}");
+            for (pos, &c) in maze.iter() {
+                let Some(dir) = Dir::from_byte(c) else {
+                    continue;
+                };
+                // going down a slope never leads into a wall
+                assert_ne!(maze[dir.step(pos)], b'#', "seed {seed}");
+            }
+        }
+    }
+}
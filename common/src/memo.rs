@@ -0,0 +1,282 @@
+//! Memoization for recursive solutions.
+//!
+//! A [`Memo`] is passed down the recursion in place of a hand-rolled cache,
+//! and [`Memo::get_or_insert_with`] wraps the part of the body worth remembering.
+//! It's backed by a `HashMap` by default, or by a flat array for keys made of
+//! small integers, see [`Memo::dense`].
+//!
+//! Running a day with `--trace` makes every memo report its hit rate to stderr once dropped.
+
+use std::{collections::HashMap, fmt, hash::Hash, marker::PhantomData, panic::Location};
+
+/// Whether the current binary was invoked with `--trace`.
+pub fn trace_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--trace")
+}
+
+/// Where a [`Memo`] keeps its values.
+pub trait Store<K, V> {
+    fn lookup(&self, key: &K) -> Option<V>;
+
+    fn store(&mut self, key: K, value: V);
+
+    fn clear(&mut self);
+}
+
+impl<K: Hash + Eq, V: Clone> Store<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self);
+    }
+}
+
+/// Keys which can index a flat array: small unsigned integers and tuples of them.
+pub trait DenseKey: Copy + fmt::Debug {
+    /// Exclusive upper bound of every component of the key.
+    type Dims: Copy + fmt::Debug;
+
+    fn len(dims: Self::Dims) -> usize;
+
+    fn index(self, dims: Self::Dims) -> Option<usize>;
+}
+
+impl DenseKey for usize {
+    type Dims = usize;
+
+    fn len(dims: usize) -> usize {
+        dims
+    }
+
+    fn index(self, dims: usize) -> Option<usize> {
+        (self < dims).then_some(self)
+    }
+}
+
+impl DenseKey for (usize, usize) {
+    type Dims = (usize, usize);
+
+    fn len((a, b): Self::Dims) -> usize {
+        a * b
+    }
+
+    fn index(self, (a, b): Self::Dims) -> Option<usize> {
+        (self.0 < a && self.1 < b).then(|| self.0 * b + self.1)
+    }
+}
+
+impl DenseKey for (usize, usize, usize) {
+    type Dims = (usize, usize, usize);
+
+    fn len((a, b, c): Self::Dims) -> usize {
+        a * b * c
+    }
+
+    fn index(self, (a, b, c): Self::Dims) -> Option<usize> {
+        (self.0 < a && self.1 < b && self.2 < c).then(|| (self.0 * b + self.1) * c + self.2)
+    }
+}
+
+/// Array backed [`Store`] for every key below some bounds.
+#[derive(Debug, Clone)]
+pub struct Dense<K: DenseKey, V> {
+    dims: K::Dims,
+    cells: Vec<Option<V>>,
+}
+
+impl<K: DenseKey, V> Dense<K, V> {
+    pub fn new(dims: K::Dims) -> Self {
+        let mut res = Dense {
+            dims,
+            cells: Vec::new(),
+        };
+        res.resize(dims);
+        res
+    }
+
+    /// Forgets every value and changes the bounds, keeping the allocation.
+    pub fn resize(&mut self, dims: K::Dims) {
+        self.dims = dims;
+        self.cells.clear();
+        self.cells.resize_with(K::len(dims), || None);
+    }
+
+    fn idx(&self, key: K) -> usize {
+        key.index(self.dims)
+            .unwrap_or_else(|| panic!("{key:?} is out of the memo's bounds {:?}", self.dims))
+    }
+}
+
+impl<K: DenseKey, V: Clone> Store<K, V> for Dense<K, V> {
+    fn lookup(&self, key: &K) -> Option<V> {
+        self.cells[self.idx(*key)].clone()
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        let idx = self.idx(key);
+        self.cells[idx] = Some(value);
+    }
+
+    fn clear(&mut self) {
+        self.cells.fill_with(|| None);
+    }
+}
+
+pub type DenseMemo<K, V> = Memo<K, V, Dense<K, V>>;
+
+/// Cache of a recursive function's results, counting how often it paid off.
+pub struct Memo<K, V, S = HashMap<K, V>> {
+    store: S,
+    hits: u64,
+    misses: u64,
+    created_at: &'static Location<'static>,
+    _marker: PhantomData<fn(K) -> V>,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self::with_store(HashMap::new())
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: DenseKey, V: Clone> DenseMemo<K, V> {
+    /// A memo for keys below `dims`, which panics when given any other key.
+    #[track_caller]
+    pub fn dense(dims: K::Dims) -> Self {
+        Self::with_store(Dense::new(dims))
+    }
+
+    /// Like [`clear`](Memo::clear), but also changes the bounds.
+    pub fn reset(&mut self, dims: K::Dims) {
+        self.store.resize(dims);
+    }
+}
+
+impl<K, V: Clone, S: Store<K, V>> Memo<K, V, S> {
+    #[track_caller]
+    pub fn with_store(store: S) -> Self {
+        Memo {
+            store,
+            hits: 0,
+            misses: 0,
+            created_at: Location::caller(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the value remembered for `key`, or computes it with `f` and remembers it.
+    ///
+    /// `f` gets the memo back, so it can recurse, along with the key,
+    /// so the key doesn't have to be cloned to be used in the computation.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.store.lookup(&key) {
+            self.hits += 1;
+            return value;
+        }
+        self.misses += 1;
+        let value = f(self, &key);
+        self.store.store(key, value.clone());
+        value
+    }
+
+    /// Forgets every value, but keeps counting hits and misses.
+    pub fn clear(&mut self) {
+        self.store.clear();
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Fraction of lookups which found a remembered value.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl<K, V, S> Drop for Memo<K, V, S> {
+    fn drop(&mut self) {
+        if trace_requested() {
+            eprintln!(
+                "memo at {}: {} hits, {} misses ({:.1}% hit rate)",
+                self.created_at,
+                self.hits,
+                self.misses,
+                self.hit_rate() * 100.,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo, &n| fib(n - 1, memo) + fib(n - 2, memo))
+    }
+
+    #[test]
+    fn hashed() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(90, &mut memo), 2880067194370816120);
+        assert_eq!(memo.misses(), 89);
+        assert_eq!(memo.hits(), 87);
+        memo.clear();
+        fib(10, &mut memo);
+        assert_eq!(memo.misses(), 98);
+    }
+
+    #[test]
+    fn dense() {
+        // number of lattice paths from (x, y) to (0, 0)
+        fn paths(pos: (usize, usize), memo: &mut DenseMemo<(usize, usize), u64>) -> u64 {
+            match pos {
+                (0, _) | (_, 0) => 1,
+                (x, y) => memo.get_or_insert_with(pos, |memo, _| {
+                    paths((x - 1, y), memo) + paths((x, y - 1), memo)
+                }),
+            }
+        }
+
+        let mut memo = DenseMemo::dense((3, 3));
+        assert_eq!(paths((2, 2), &mut memo), 6);
+        assert_eq!(memo.hits(), 1);
+        memo.reset((17, 17));
+        assert_eq!(paths((16, 16), &mut memo), 601080390);
+        assert!(memo.hit_rate() > 0.4);
+    }
+
+    #[test]
+    #[should_panic = "out of the memo's bounds"]
+    fn dense_out_of_bounds() {
+        let mut memo = DenseMemo::<usize, ()>::dense(4);
+        memo.get_or_insert_with(4, |_, _| ());
+    }
+}
@@ -0,0 +1,150 @@
+//! Parsers for the input shapes that keep coming back, e.g. blank-line separated sections,
+//! `label: 1 2 3` lines, or `x,y` coordinates.
+//!
+//! Everything returns an error describing the offending piece of input instead of panicking.
+
+use std::str::FromStr;
+
+use aoc_lib::{
+    color_eyre::eyre::{Result, eyre},
+    grid::Point,
+    to_lines,
+};
+
+/// Parses a single, surrounding whitespace trimmed, value.
+pub fn value<T: FromStr>(s: &str) -> Result<T> {
+    let s = s.trim();
+    s.parse().map_err(|_| {
+        eyre!(
+            "couldn't parse {s:?} as {}",
+            std::any::type_name::<T>().rsplit("::").next().unwrap()
+        )
+    })
+}
+
+/// Sections of the input separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input.trim().split("\n\n")
+}
+
+/// Exactly `N` sections of the input separated by blank lines.
+pub fn split_sections<const N: usize>(input: &str) -> Result<[&str; N]> {
+    exactly(sections(input), "sections")
+}
+
+/// Exactly `N` whitespace separated words.
+pub fn words<const N: usize>(s: &str) -> Result<[&str; N]> {
+    exactly(s.split_ascii_whitespace(), "words")
+}
+
+fn exactly<'a, const N: usize>(
+    iter: impl Iterator<Item = &'a str>,
+    what: &str,
+) -> Result<[&'a str; N]> {
+    let parts: Vec<_> = iter.collect();
+    let len = parts.len();
+    parts
+        .try_into()
+        .map_err(|_| eyre!("expected {N} {what}, found {len}"))
+}
+
+/// Splits `label: value` into the label and the trimmed value.
+pub fn labeled(line: &str) -> Result<(&str, &str)> {
+    let (label, value) = line
+        .split_once(':')
+        .ok_or_else(|| eyre!("expected `label: value`, found {line:?}"))?;
+    Ok((label.trim(), value.trim()))
+}
+
+/// Whitespace separated values.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>> {
+    s.split_ascii_whitespace().map(value).collect()
+}
+
+/// Values separated by `sep`, e.g. `1,2,3`.
+pub fn separated<T: FromStr>(s: &str, sep: &str) -> Result<Vec<T>> {
+    s.trim().split(sep).map(value).collect()
+}
+
+/// A `label: 1 2 3` line.
+pub fn labeled_numbers<T: FromStr>(line: &str) -> Result<(&str, Vec<T>)> {
+    let (label, nums) = labeled(line)?;
+    Ok((label, numbers(nums)?))
+}
+
+/// Two values separated by `sep`, e.g. `3|5` or `1, 2`.
+pub fn pair<T: FromStr>(s: &str, sep: &str) -> Result<(T, T)> {
+    let (a, b) = s
+        .split_once(sep)
+        .ok_or_else(|| eyre!("expected two values separated by {sep:?}, found {s:?}"))?;
+    Ok((value(a)?, value(b)?))
+}
+
+/// An `x,y` or `x, y` coordinate.
+pub fn point(s: &str) -> Result<Point> {
+    pair(s, ",")
+}
+
+/// One `x,y` coordinate per line.
+pub fn points(input: &str) -> Result<Vec<Point>> {
+    to_lines(input).map(point).collect()
+}
+
+/// An `a-b` edge between two named nodes.
+pub fn edge(s: &str) -> Result<(&str, &str)> {
+    s.trim()
+        .split_once('-')
+        .ok_or_else(|| eyre!("expected `a-b`, found {s:?}"))
+}
+
+/// All the integers in `s`, ignoring whatever is around them,
+/// so `Button A: X+94, Y-34` gives `[94, -34]`.
+pub fn ints(s: &str) -> impl Iterator<Item = i64> + '_ {
+    s.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter_map(|n| n.parse().ok())
+}
+
+/// Exactly `N` integers found in `s`, see [`ints`].
+pub fn n_ints<const N: usize>(s: &str) -> Result<[i64; N]> {
+    let found: Vec<_> = ints(s).collect();
+    found
+        .try_into()
+        .map_err(|found: Vec<_>| eyre!("expected {N} numbers in {s:?}, found {}", found.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_and_labels() {
+        let input = "\nSeeds: 1 2 3\n\na\nb\n";
+        let [seeds, rest] = split_sections(input).unwrap();
+        assert_eq!(rest, "a\nb");
+        assert_eq!(
+            labeled_numbers::<u32>(seeds).unwrap(),
+            ("Seeds", vec![1, 2, 3])
+        );
+        assert!(split_sections::<3>(input).is_err());
+        assert!(labeled("no label").is_err());
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(pair::<u8>("47|53", "|").unwrap(), (47, 53));
+        assert_eq!(point("3, -4").unwrap(), (3, -4));
+        assert_eq!(points("1,2\n3,4\n").unwrap(), [(1, 2), (3, 4)]);
+        assert_eq!(separated::<u8>("0,3,5", ",").unwrap(), [0, 3, 5]);
+        assert_eq!(edge("kh-tc").unwrap(), ("kh", "tc"));
+        assert_eq!(words("x00 AND y00").unwrap(), ["x00", "AND", "y00"]);
+        let err = numbers::<u8>("1 x 3").unwrap_err();
+        assert_eq!(err.to_string(), "couldn't parse \"x\" as u8");
+    }
+
+    #[test]
+    fn embedded_ints() {
+        assert_eq!(n_ints("p=0,4 v=3,-3").unwrap(), [0, 4, 3, -3]);
+        assert_eq!(n_ints("Button A: X+94, Y+34").unwrap(), [94, 34]);
+        assert!(n_ints::<3>("Register A: 729").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Random pipe mazes for 2023 day 10, with both answers worked out from how they were built.
+//!
+//! The loop is the outline of a random region of the unit squares between the tiles' centers,
+//! grown one square at a time and only ever into squares which keep the outline a single loop.
+//! Going around the region, the loop is as long as its perimeter,
+//! and the tiles it encloses are the ones with all four squares around them inside the region.
+
+use std::collections::HashMap;
+
+use aoc_lib::grid::Point;
+
+use crate::{dir::Dir, grid_buf::GridBuf, rng::Rng};
+
+#[derive(Debug, Clone)]
+pub struct PipeMaze {
+    /// The maze, in the puzzle's input format.
+    pub input: String,
+    /// How many steps along the loop the farthest pipe is from `S`.
+    pub farthest: i64,
+    /// How many tiles the loop encloses.
+    pub enclosed: i64,
+}
+
+/// The squares around a square, going clockwise from the one above it.
+const RING: [Point; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A `width` by `height` maze with the loop through `S` running through random pipes.
+pub fn pipe_maze(width: i64, height: i64, rng: &mut Rng) -> PipeMaze {
+    assert!(
+        width >= 2 && height >= 2,
+        "loops need at least 2x2 tiles, not {width}x{height}"
+    );
+    // square (x, y) has the tiles (x, y) and (x + 1, y + 1) at its opposite corners
+    let mut region = GridBuf::new(width as usize - 1, height as usize - 1, false);
+    let size = rng.below(((width - 1) * (height - 1)) as u64) + 1;
+    let first = (rng.range(0..region.width()), rng.range(0..region.height()));
+    region[first] = true;
+    let mut area = 1;
+    let mut frontier: Vec<_> = region.orthogonal_pos(first).collect();
+    while area < size && !frontier.is_empty() {
+        let square = frontier.swap_remove(rng.below(frontier.len() as u64) as usize);
+        if region[square] || !keeps_single_loop(&region, square) {
+            continue;
+        }
+        region[square] = true;
+        area += 1;
+        frontier.extend(region.orthogonal_pos(square).filter(|&pos| !region[pos]));
+    }
+
+    // every side of the region joins two tiles next to each other on the loop
+    let mut pipes = HashMap::<Point, Vec<Dir>>::new();
+    for ((x, y), _) in region.iter().filter(|&(_, &inside)| inside) {
+        let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+        for (i, side) in Dir::ALL.into_iter().enumerate() {
+            if region.get(side.step((x, y))).is_some_and(|&inside| inside) {
+                continue;
+            }
+            let (from, to) = (corners[i], corners[(i + 1) % 4]);
+            let dir = Dir::from_delta((to.0 - from.0, to.1 - from.1)).unwrap();
+            pipes.entry(from).or_default().push(dir);
+            pipes.entry(to).or_default().push(dir.turn_around());
+        }
+    }
+
+    let mut tiles = GridBuf::new(width as usize, height as usize, b'.');
+    let mut loop_tiles: Vec<_> = pipes.keys().copied().collect();
+    // sorted, so the start doesn't depend on the map's iteration order
+    loop_tiles.sort();
+    for &tile in &loop_tiles {
+        tiles[tile] = pipe(&pipes[&tile]);
+    }
+    let start = *rng.pick(&loop_tiles).unwrap();
+    tiles[start] = b'S';
+
+    let junk = *b".|-LJ7F";
+    for y in 0..height {
+        for x in 0..width {
+            if pipes.contains_key(&(x, y)) {
+                continue;
+            }
+            // only the loop's own pipes can lead into the start, or it would be ambiguous
+            tiles[(x, y)] = loop {
+                let c = *rng.pick(&junk).unwrap();
+                if !connections(c).any(|dir| dir.step((x, y)) == start) {
+                    break c;
+                }
+            };
+        }
+    }
+
+    let enclosed = (1..height - 1)
+        .flat_map(|y| (1..width - 1).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            [(x - 1, y - 1), (x, y - 1), (x - 1, y), (x, y)]
+                .into_iter()
+                .all(|square| region[square])
+        })
+        .count();
+
+    PipeMaze {
+        input: tiles.to_string(),
+        farthest: loop_tiles.len() as i64 / 2,
+        enclosed: enclosed as i64,
+    }
+}
+
+/// Whether adding `square` to `region` leaves its outline a single loop.
+///
+/// That's the case when the squares around it already in the region form a single unbroken run,
+/// touching it along a side: otherwise it would either join parts of the region around a hole,
+/// or meet a square only at a corner, which the loop would then go through twice.
+fn keeps_single_loop(region: &GridBuf<bool>, (x, y): Point) -> bool {
+    let inside = RING.map(|(dx, dy)| region.get((x + dx, y + dy)).is_some_and(|&inside| inside));
+    let runs = (0..8)
+        .filter(|&i| inside[i] && !inside[(i + 7) % 8])
+        .count();
+    let touches_side = (0..8).step_by(2).any(|i| inside[i]);
+    runs == 1 && touches_side
+}
+
+/// The pipe going off in both `dirs`.
+fn pipe(dirs: &[Dir]) -> u8 {
+    assert_eq!(dirs.len(), 2, "the loop goes through a tile more than once");
+    b"|-LJ7F"
+        .iter()
+        .copied()
+        .find(|&c| connections(c).all(|dir| dirs.contains(&dir)))
+        .unwrap()
+}
+
+fn connections(pipe: u8) -> impl Iterator<Item = Dir> {
+    let dirs: &[Dir] = match pipe {
+        b'|' => &[Dir::Up, Dir::Down],
+        b'-' => &[Dir::Left, Dir::Right],
+        b'L' => &[Dir::Up, Dir::Right],
+        b'J' => &[Dir::Up, Dir::Left],
+        b'7' => &[Dir::Left, Dir::Down],
+        b'F' => &[Dir::Right, Dir::Down],
+        _ => &[],
+    };
+    dirs.iter().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let maze = |seed| pipe_maze(12, 8, &mut Rng::new(seed)).input;
+        assert_eq!(maze(1), maze(1));
+        assert_ne!(maze(1), maze(2));
+    }
+
+    #[test]
+    fn single_loop() {
+        for seed in 0..200 {
+            let maze = pipe_maze(15, 10, &mut Rng::new(seed));
+            let tiles = GridBuf::for_str(&maze.input).unwrap();
+            let start = tiles.find(&b'S').unwrap();
+
+            // every pipe leading into the start is on the loop, so following either goes around it
+            let exits: Vec<_> = Dir::ALL
+                .into_iter()
+                .filter(|&dir| {
+                    let next = tiles.get(dir.step(start));
+                    next.is_some_and(|&c| connections(c).any(|back| back == dir.turn_around()))
+                })
+                .collect();
+            assert_eq!(exits.len(), 2, "seed {seed}");
+
+            let (mut pos, mut dir) = (exits[0].step(start), exits[0]);
+            let mut len = 1;
+            while pos != start {
+                dir = connections(tiles[pos])
+                    .find(|&next| next != dir.turn_around())
+                    .unwrap();
+                pos = dir.step(pos);
+                len += 1;
+            }
+            assert_eq!(len, maze.farthest * 2, "seed {seed}");
+            assert_eq!(dir.turn_around(), exits[1], "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Timing tables for the years' readmes, so how fast every day is sits next to its code,
+//! and the history of the readme shows what each optimization won over the first solution.
+//!
+//! The `report` example builds both years, runs every day with `--repeat`,
+//! and writes a table of the parts' median times between the [`START`] and [`END`] markers
+//! of each year's readme, comparing them with the table it replaces.
+//! From the repository's root: `cargo timings`, or `cargo timings 20` for 20 runs of every part.
+
+use std::time::Duration;
+
+pub const START: &str = "<!-- timings -->";
+pub const END: &str = "<!-- /timings -->";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    /// Like `01`.
+    pub day: String,
+    /// Like `1`.
+    pub part: String,
+    pub median: Duration,
+}
+
+/// The timings in what a day's binary printed to stderr when run with `--repeat`.
+pub fn timings(day: &str, stderr: &str) -> Vec<Timing> {
+    stderr
+        .lines()
+        .filter_map(|line| {
+            let (part, rest) = line.strip_prefix("part")?.split_once(": ")?;
+            let (_, rest) = rest.split_once(" runs, ")?;
+            let (median, _) = rest.split_once(" median")?;
+            Some(Timing {
+                day: day.to_string(),
+                part: part.to_string(),
+                median: parse_duration(median)?,
+            })
+        })
+        .collect()
+}
+
+/// The timings in the table between the markers of `readme`.
+pub fn previous(readme: &str) -> Vec<Timing> {
+    let Some((_, table)) = readme.split_once(START) else {
+        return Vec::new();
+    };
+    let table = table.split_once(END).map_or(table, |(table, _)| table);
+    table
+        .lines()
+        .filter_map(|row| {
+            let cells: Vec<_> = row.split('|').map(str::trim).collect();
+            let [_, day, part, median, ..] = cells[..] else {
+                return None;
+            };
+            Some(Timing {
+                day: day.to_string(),
+                part: part.to_string(),
+                median: parse_duration(median)?,
+            })
+        })
+        .collect()
+}
+
+/// A markdown table of `timings`, with how they changed since the `previous` ones.
+pub fn table(timings: &[Timing], previous: &[Timing]) -> String {
+    let mut res = String::from("| day | part | median | change |\n| --: | --: | --: | --: |\n");
+    for timing in timings {
+        let before = previous
+            .iter()
+            .find(|before| (&before.day, &before.part) == (&timing.day, &timing.part));
+        let change = match before {
+            Some(before) if !before.median.is_zero() => {
+                let ratio = timing.median.as_secs_f64() / before.median.as_secs_f64();
+                format!("{:+.0}%", (ratio - 1.) * 100.)
+            }
+            _ => "new".to_string(),
+        };
+        res += &format!(
+            "| {} | {} | {:.1?} | {change} |\n",
+            timing.day, timing.part, timing.median
+        );
+    }
+    res
+}
+
+/// `readme` with `table` in place of whatever was between its markers,
+/// or in a new section at its end if it doesn't have them yet.
+pub fn splice(readme: &str, table: &str) -> String {
+    let marked = readme
+        .split_once(START)
+        .and_then(|(before, rest)| Some((before, rest.split_once(END)?.1)));
+    match marked {
+        Some((before, after)) => format!("{before}{START}\n{table}{END}{after}"),
+        None => format!(
+            "{}\n\n## timings\n{START}\n{table}{END}\n",
+            readme.trim_end()
+        ),
+    }
+}
+
+/// Reads a duration printed with `{:.1?}`, like `12.3ms`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (n, unit) = s.split_at(split);
+    let n: f64 = n.parse().ok()?;
+    let secs = match unit {
+        "s" => n,
+        "ms" => n / 1e3,
+        "µs" => n / 1e6,
+        "ns" => n / 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: &str, part: &str, micros: u64) -> Timing {
+        Timing {
+            day: day.to_string(),
+            part: part.to_string(),
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn durations() {
+        for duration in [
+            Duration::from_nanos(42),
+            Duration::from_micros(1500),
+            Duration::from_millis(250),
+            Duration::from_secs(3),
+        ] {
+            assert_eq!(parse_duration(&format!("{duration:.1?}")), Some(duration));
+        }
+        assert_eq!(parse_duration("12.3 ms"), None);
+        assert_eq!(parse_duration("ms"), None);
+    }
+
+    #[test]
+    fn parsed() {
+        let stderr = "part1: 5 runs, 1.5ms median, 1.6ms on average, 1.4ms at best\n\
+                      some other line\n\
+                      part2: 5 runs, 250.0µs median, 260.0µs on average, 240.0µs at best\n";
+        assert_eq!(
+            timings("07", stderr),
+            [timing("07", "1", 1500), timing("07", "2", 250)]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let first = [timing("01", "1", 1000), timing("01", "2", 2000)];
+        let readme = splice("## philosophy\nfirst solutions stay\n", &table(&first, &[]));
+        assert!(readme.starts_with("## philosophy\nfirst solutions stay\n\n## timings\n"));
+        assert!(readme.contains("| 01 | 2 | 2.0ms | new |"));
+        assert_eq!(previous(&readme), first);
+
+        let second = [
+            timing("01", "1", 500),
+            timing("01", "2", 2000),
+            timing("02", "1", 10),
+        ];
+        let updated = splice(&readme, &table(&second, &previous(&readme)));
+        assert!(updated.contains("| 01 | 1 | 500.0µs | -50% |"));
+        assert!(updated.contains("| 01 | 2 | 2.0ms | +0% |"));
+        assert!(updated.contains("| 02 | 1 | 10.0µs | new |"));
+        assert_eq!(previous(&updated), second);
+        assert_eq!(updated.matches(START).count(), 1);
+    }
+}
@@ -0,0 +1,97 @@
+//! Small seeded random number generator, for generating puzzle inputs to test the days on.
+//!
+//! It's SplitMix64, which is nowhere near good enough for anything cryptographic,
+//! but fast, and gives the same numbers on every platform,
+//! so whatever was generated from a seed can always be generated again from it.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which mustn't be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "no numbers below 0");
+        // the high half of the product is close enough to uniform for numbers this much smaller than 2^64
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// A number in `range`, which mustn't be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "empty range {range:?}");
+        range.start + self.below(range.start.abs_diff(range.end)) as i64
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        // 53 random bits, as many as fit in the mantissa of an `f64` in `0.0..1.0`
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.below(items.len() as u64) as usize)
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            [(); 4].map(|_| rng.next_u64())
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+    }
+
+    #[test]
+    fn in_range() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let n = rng.range(-3..4);
+            assert!((-3..4).contains(&n));
+            seen[(n + 3) as usize] = true;
+        }
+        assert_eq!(seen, [true; 7]);
+        assert!((0..100).all(|_| !rng.chance(0.0) && rng.chance(1.0)));
+        assert_eq!(rng.pick::<u8>(&[]), None);
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = Rng::new(0);
+        let mut items: Vec<_> = (0..100).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,593 @@
+//! Entry point for the days' binaries, which are thin wrappers around the yearly libraries.
+//!
+//! ```ignore
+//! use aoc2024::day01::{part1, part2};
+//!
+//! static INPUT: &str = include_str!("../../inputs/day01/input");
+//!
+//! aoc_common::main! {
+//!     INPUT: part1, part2
+//! }
+//! ```
+//!
+//! Running a day with `--batch` runs it on every input in its `inputs/dayNN` directory instead.
+//! When an input has a `<name>.expected` file next to it, with lines like the binaries print,
+//! e.g. `part1: 42`, the answers are checked against it.
+//! The run fails if any input got a wrong answer, an error, or a panic.
+//!
+//! Answers are cached in the crate's `target/answers`, keyed by the input, the part,
+//! the binary solving it and its arguments, so unchanged days answer instantly.
+//! `--no-cache` bypasses the cache, and `--verify-cache` solves the parts anyway,
+//! failing if they don't agree with the cached answers.
+//! Flags asking the days for more than their answers, like `--dot`, `--trace` and `--step`,
+//! bypass the cache too, since an answer coming from it would skip whatever they show.
+//!
+//! A part can have other implementations next to it, e.g. the first solution of a day
+//! which has been optimized since, as functions in a module named after the part:
+//!
+//! ```ignore
+//! aoc_common::main! {
+//!     INPUT: part1 { original }, part2
+//! }
+//! ```
+//!
+//! Only the part itself is run normally. With `--compare`, every implementation is run on every input,
+//! checking they agree with the part and showing how fast they are compared to it.
+//! They're run on the examples in the day's tests too, read out of `src/dayNN.rs`,
+//! skipping the ones the part itself fails on, like another part's examples.
+//!
+//! A few more flags help scripts and benchmarks:
+//!
+//! - `--part 1` only runs part 1, in any of the modes above.
+//! - `--repeat 10` solves each part 10 times, uncached, failing unless every run agrees,
+//!   and reports how long the runs took on stderr: their median, mean and the fastest one.
+//! - `--expect 42` fails the run, showing where the answer differs, unless the part answers 42.
+//!   It's given once per part run, in order, written the way the answer is printed,
+//!   e.g. `--expect 4,6,3,5`, and can't be combined with `--batch` or `--compare`,
+//!   which check the answers their own way.
+//!
+//! Parts can answer with anything which converts into an [`Answer`](crate::answer::Answer),
+//! which is how it's printed, cached, and compared with what's expected.
+
+use std::{
+    collections::HashMap,
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{self, IsTerminal},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+#[doc(hidden)]
+pub use aoc_lib::color_eyre::eyre::Result;
+use aoc_lib::color_eyre::eyre::{bail, eyre};
+use crossterm::style::{Color, Stylize};
+
+/// Defines `main`, which runs every listed part on the input and prints its answer.
+#[macro_export]
+macro_rules! main {
+    ($input:ident : $($part:ident $({ $($other:ident),+ $(,)? })?),+ $(,)?) => {
+        fn main() -> $crate::run::Result<()> {
+            $crate::run::run(
+                $input,
+                env!("CARGO_MANIFEST_DIR"),
+                env!("CARGO_BIN_NAME"),
+                &[$((
+                    stringify!($part),
+                    &|input| $part(input).map(|res| $crate::answer::Answer::from(res).to_string()),
+                )),+],
+                &[$($($((
+                    concat!(stringify!($part), "::", stringify!($other)),
+                    &|input| {
+                        $part::$other(input).map(|res| $crate::answer::Answer::from(res).to_string())
+                    },
+                ),)+)?)+],
+            )
+        }
+    };
+}
+
+/// A part's name, and its solution rendering the answer the way it's printed.
+/// Other implementations of a part are named `part::implementation`.
+pub type Part<'a> = (&'a str, &'a dyn Fn(&str) -> Result<String>);
+
+fn arg_given(arg: &str) -> bool {
+    std::env::args().skip(1).any(|given| given == arg)
+}
+
+/// The values given after every `flag`, e.g. `["1"]` for `--part 1`.
+fn arg_values(flag: &str) -> Vec<String> {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    args.windows(2)
+        .filter(|pair| pair[0] == flag)
+        .map(|pair| pair[1].clone())
+        .collect()
+}
+
+/// The runner's flags which are followed by a value.
+const FLAGS_WITH_VALUES: [&str; 3] = ["--part", "--repeat", "--expect"];
+
+/// Whether the current binary was invoked with `--batch`.
+pub fn batch_requested() -> bool {
+    arg_given("--batch")
+}
+
+#[doc(hidden)]
+pub fn run(input: &str, crate_dir: &str, day: &str, parts: &[Part], others: &[Part]) -> Result<()> {
+    let crate_dir = Path::new(crate_dir);
+    let inputs_dir = crate_dir.join("inputs").join(day);
+    let parts = selected(parts)?;
+    let expected = arg_values("--expect");
+    if !expected.is_empty() && expected.len() != parts.len() {
+        bail!(
+            "--expect given {} times, but running {} parts",
+            expected.len(),
+            parts.len()
+        );
+    }
+    if arg_given("--compare") {
+        if !expected.is_empty() {
+            bail!(
+                "--expect doesn't apply to --compare, which checks the implementations agree instead"
+            );
+        }
+        let examples = examples(crate_dir, day);
+        let failed = compare(inputs(&inputs_dir)?, examples, &parts, others);
+        if !failed.is_empty() {
+            bail!("implementations disagree on {}", failed.join(", "));
+        }
+        return Ok(());
+    }
+
+    let cache = Cache::new(crate_dir.join("target/answers"))?;
+    if batch_requested() {
+        if !expected.is_empty() {
+            bail!("--expect doesn't apply to --batch, which checks the .expected files instead");
+        }
+        let failed = batch(&inputs_dir, &parts, cache.as_ref())?;
+        if !failed.is_empty() {
+            bail!("failed on {}", failed.join(", "));
+        }
+        return Ok(());
+    }
+
+    let repeat = match arg_values("--repeat").last() {
+        Some(repeat) => Some(
+            repeat
+                .parse()
+                .ok()
+                .filter(|&repeat| repeat > 0)
+                .ok_or_else(|| eyre!("can't repeat {repeat} times"))?,
+        ),
+        None => None,
+    };
+    let mut wrong = Vec::new();
+    for (i, (name, part)) in parts.iter().enumerate() {
+        let answer = match repeat {
+            // even a single run is timed, for `report` to read
+            Some(times) => repeated(name, part, input, times)?,
+            None => answer(name, part, input, cache.as_ref())?,
+        };
+        println!("{name}: {answer}");
+        if let Some(expected) = expected.get(i).filter(|&expected| *expected != answer) {
+            println!("{}", diff(expected, &answer, io::stdout().is_terminal()));
+            wrong.push(*name);
+        }
+    }
+    if !wrong.is_empty() {
+        bail!("wrong answer for {}", wrong.join(", "));
+    }
+    Ok(())
+}
+
+/// The parts picked with `--part`, or all of them.
+fn selected<'a>(parts: &[Part<'a>]) -> Result<Vec<Part<'a>>> {
+    let picked = arg_values("--part");
+    let Some(number) = picked.last() else {
+        return Ok(parts.to_vec());
+    };
+    let name = format!("part{number}");
+    let part: Vec<_> = parts
+        .iter()
+        .filter(|(part, _)| *part == name)
+        .copied()
+        .collect();
+    if part.is_empty() {
+        bail!("there's no part {number}");
+    }
+    Ok(part)
+}
+
+/// Solves a part `times` times, uncached, reporting how long it took on stderr.
+fn repeated(
+    name: &str,
+    part: impl Fn(&str) -> Result<String>,
+    input: &str,
+    times: usize,
+) -> Result<String> {
+    let mut first = None;
+    let mut durations = Vec::with_capacity(times);
+    for run in 1..=times {
+        let start = Instant::now();
+        let answer = part(input)?;
+        durations.push(start.elapsed());
+        match &first {
+            None => first = Some(answer),
+            Some(first) if *first != answer => {
+                bail!("{name} answered {first} on the first run, but {answer} on run {run}")
+            }
+            Some(_) => {}
+        }
+    }
+    let mean = durations.iter().sum::<Duration>() / times as u32;
+    durations.sort();
+    let median = durations[times / 2];
+    let fastest = durations[0];
+    // read back by `report::timings`, so mind it when changing this
+    eprintln!(
+        "{name}: {times} runs, {median:.1?} median, {mean:.1?} on average, {fastest:.1?} at best"
+    );
+    Ok(first.unwrap())
+}
+
+/// How many bytes `a` and `b` start and end with in common, not counting any twice.
+fn common_ends(a: &str, b: &str) -> (usize, usize) {
+    fn common(a: impl Iterator<Item = char>, b: impl Iterator<Item = char>) -> usize {
+        a.zip(b)
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum()
+    }
+    let prefix = common(a.chars(), b.chars());
+    let suffix = common(a[prefix..].chars().rev(), b[prefix..].chars().rev());
+    (prefix, suffix)
+}
+
+/// The expected and the actual answer, one above the other,
+/// with where they differ colored if `color` is set.
+fn diff(expected: &str, answer: &str, color: bool) -> String {
+    let (prefix, suffix) = common_ends(expected, answer);
+    let line = |label: &str, s: &str, highlight: Color| {
+        let middle = &s[prefix..s.len() - suffix];
+        let middle = if color {
+            middle.with(highlight).to_string()
+        } else {
+            middle.to_string()
+        };
+        format!(
+            "  {label:<10}{}{middle}{}",
+            &s[..prefix],
+            &s[s.len() - suffix..]
+        )
+    };
+    format!(
+        "{}\n{}",
+        line("expected:", expected, Color::Green),
+        line("got:", answer, Color::Red)
+    )
+}
+
+fn answer(
+    name: &str,
+    part: impl Fn(&str) -> Result<String>,
+    input: &str,
+    cache: Option<&Cache>,
+) -> Result<String> {
+    match cache {
+        Some(cache) => cache.answer(name, input, || part(input)),
+        None => part(input),
+    }
+}
+
+/// Answers remembered between runs.
+struct Cache {
+    dir: PathBuf,
+    exe_hash: u64,
+    verify: bool,
+}
+
+impl Cache {
+    /// The cache in `dir`, unless it's being bypassed.
+    fn new(dir: PathBuf) -> Result<Option<Self>> {
+        let bypassed = ["--no-cache", "--repeat", "--dot", "--trace", "--step"];
+        if bypassed.into_iter().any(arg_given) {
+            return Ok(None);
+        }
+        let mut hasher = DefaultHasher::new();
+        fs::read(std::env::current_exe()?)?.hash(&mut hasher);
+        Ok(Some(Cache {
+            dir,
+            exe_hash: hasher.finish(),
+            verify: arg_given("--verify-cache"),
+        }))
+    }
+
+    fn answer(
+        &self,
+        part: &str,
+        input: &str,
+        solve: impl FnOnce() -> Result<String>,
+    ) -> Result<String> {
+        let mut hasher = DefaultHasher::new();
+        (self.exe_hash, part, input).hash(&mut hasher);
+        // leaving out the flags which don't change how the part is solved
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if FLAGS_WITH_VALUES.contains(&arg.as_str()) {
+                args.next();
+            } else if !matches!(arg.as_str(), "--batch" | "--verify-cache") {
+                arg.hash(&mut hasher);
+            }
+        }
+        let path = self.dir.join(format!("{:016x}", hasher.finish()));
+
+        match fs::read_to_string(&path).ok() {
+            Some(cached) if !self.verify => Ok(cached),
+            Some(cached) => {
+                let answer = solve()?;
+                if answer != cached {
+                    bail!("{part} answered {answer}, but {cached} is cached for it");
+                }
+                Ok(answer)
+            }
+            None => {
+                let answer = solve()?;
+                fs::create_dir_all(&self.dir)?;
+                fs::write(&path, &answer)?;
+                Ok(answer)
+            }
+        }
+    }
+}
+
+/// Names and contents of the inputs in `dir`.
+fn inputs(dir: &Path) -> Result<Vec<(String, String)>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_none_or(|ext| ext != "expected") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut res = Vec::new();
+    for path in paths {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        res.push((name, fs::read_to_string(&path)?));
+    }
+    Ok(res)
+}
+
+/// Solves a part, describing what went wrong if it errors or panics.
+fn attempt(
+    name: &str,
+    part: impl Fn(&str) -> Result<String>,
+    input: &str,
+    cache: Option<&Cache>,
+) -> Result<String, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| answer(name, part, input, cache))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(format!("error: {err}")),
+        Err(_) => Err("panicked".to_string()),
+    }
+}
+
+/// Runs every part on every input in `dir`, printing how it went,
+/// and returns the names of the inputs which any of them failed on.
+fn batch(dir: &Path, parts: &[Part], cache: Option<&Cache>) -> Result<Vec<String>> {
+    let mut failed = Vec::new();
+    for (name, input) in inputs(dir)? {
+        let expected = dir.join(format!("{name}.expected"));
+        let expected = fs::read_to_string(expected).unwrap_or_default();
+        let expected: HashMap<_, _> = expected
+            .lines()
+            .filter_map(|line| line.split_once(": "))
+            .collect();
+
+        let mut ok = true;
+        for (part, solve) in parts {
+            let outcome = match attempt(part, solve, &input, cache) {
+                Ok(answer) => match expected.get(part) {
+                    Some(&expected) if expected != answer => {
+                        ok = false;
+                        format!("{answer}, expected {expected}")
+                    }
+                    _ => answer,
+                },
+                Err(err) => {
+                    ok = false;
+                    err
+                }
+            };
+            println!("{name} {part}: {outcome}");
+        }
+        if !ok {
+            failed.push(name);
+        }
+    }
+
+    Ok(failed)
+}
+
+/// The examples in the tests of `day`'s module, named like `example EX_INPUT`.
+fn examples(crate_dir: &Path, day: &str) -> Vec<(String, String)> {
+    let source = crate_dir.join("src").join(format!("{day}.rs"));
+    let source = fs::read_to_string(source).unwrap_or_default();
+    crate::examples::from_source(&source)
+        .into_iter()
+        .map(|(name, example)| (format!("example {name}"), example))
+        .collect()
+}
+
+/// Runs every part and its other implementations on every input and example, uncached,
+/// printing their answers and timings, and returns the names of the ones they disagreed on.
+///
+/// Examples the part itself fails on are skipped, since they may well be meant for another part.
+fn compare(
+    inputs: Vec<(String, String)>,
+    examples: Vec<(String, String)>,
+    parts: &[Part],
+    others: &[Part],
+) -> Vec<String> {
+    let inputs = inputs.into_iter().map(|input| (input, false));
+    let examples = examples.into_iter().map(|example| (example, true));
+    let mut failed = Vec::new();
+    for ((name, input), is_example) in inputs.chain(examples) {
+        let mut ok = true;
+        for (part, solve) in parts {
+            let start = Instant::now();
+            let answer = attempt(part, solve, &input, None);
+            let part_time = start.elapsed();
+            match &answer {
+                Ok(answer) => println!("{name} {part}: {answer} in {part_time:.1?}"),
+                Err(err) if is_example => {
+                    println!("{name} {part}: {err}, skipped");
+                    continue;
+                }
+                Err(err) => {
+                    ok = false;
+                    println!("{name} {part}: {err}");
+                }
+            }
+
+            let implementations = others.iter().filter(|(other, _)| {
+                other
+                    .strip_prefix(part)
+                    .is_some_and(|rest| rest.starts_with("::"))
+            });
+            for (other, solve) in implementations {
+                let start = Instant::now();
+                let other_answer = attempt(other, solve, &input, None);
+                let time = start.elapsed();
+                let speed = match time.as_secs_f64() / part_time.as_secs_f64() {
+                    ratio if ratio >= 1. => format!("{ratio:.1}x slower"),
+                    ratio => format!("{:.1}x faster", 1. / ratio),
+                };
+                match other_answer {
+                    Ok(other_answer) if answer.as_ref() == Ok(&other_answer) => {
+                        println!("{name} {other}: {other_answer} in {time:.1?}, {speed}")
+                    }
+                    Ok(other_answer) => {
+                        ok = false;
+                        println!("{name} {other}: {other_answer}, disagreeing with {part}");
+                    }
+                    Err(err) => {
+                        ok = false;
+                        println!("{name} {other}: {err}");
+                    }
+                }
+            }
+        }
+        if !ok {
+            failed.push(name);
+        }
+    }
+
+    failed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_reports_failures() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a"), "1 2").unwrap();
+        fs::write(dir.join("a.expected"), "sum: 3\n").unwrap();
+        fs::write(dir.join("b"), "2 2").unwrap();
+        fs::write(dir.join("b.expected"), "sum: 5\n").unwrap();
+        fs::write(dir.join("c"), "2 x").unwrap();
+        fs::write(dir.join("d"), "7 7").unwrap();
+        // checked against `e.txt.expected`, not `e.expected`
+        fs::write(dir.join("e.txt"), "1 1").unwrap();
+        fs::write(dir.join("e.txt.expected"), "sum: 3\n").unwrap();
+        fs::write(dir.join("e.expected"), "sum: 2\n").unwrap();
+
+        let sum = |input: &str| -> Result<String> {
+            let mut res = 0;
+            for n in input.split(' ') {
+                res += n.parse::<i64>()?;
+            }
+            Ok(res.to_string())
+        };
+        let failed = batch(&dir, &[("sum", &sum)], None);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(failed.unwrap(), ["b", "c", "e.txt"]);
+    }
+
+    #[test]
+    fn compare_checks_examples() {
+        let sum = |input: &str| -> Result<String> {
+            let mut res = 0;
+            for n in input.split(' ') {
+                res += n.parse::<i64>()?;
+            }
+            Ok(res.to_string())
+        };
+        // only wrong on inputs with more than two numbers
+        let pairs = |input: &str| -> Result<String> {
+            let nums: Vec<_> = input.split(' ').collect();
+            sum(&nums[..nums.len().min(2)].join(" "))
+        };
+        let owned = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|&(name, input)| (name.to_string(), input.to_string()))
+                .collect()
+        };
+
+        let failed = compare(
+            owned(&[("input", "1 2")]),
+            owned(&[("example A", "1 2 3"), ("example B", "1 x")]),
+            &[("sum", &sum)],
+            &[("sum::pairs", &pairs)],
+        );
+        assert_eq!(failed, ["example A"]);
+    }
+
+    #[test]
+    fn expected_answers() {
+        assert_eq!(common_ends("1,2,3,4", "1,2,7,4"), (4, 2));
+        assert_eq!(common_ends("1234", "12345"), (4, 0));
+        assert_eq!(common_ends("aba", "abba"), (2, 1));
+        assert_eq!(common_ends("é1é", "é2é"), (2, 2));
+        assert_eq!(common_ends("42", "42"), (2, 0));
+
+        assert_eq!(
+            diff("1,5,3", "1,6,3", false),
+            "  expected: 1,5,3\n  got:      1,6,3"
+        );
+    }
+
+    #[test]
+    fn cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let mut cache = Cache {
+            dir: dir.clone(),
+            exe_hash: 0,
+            verify: false,
+        };
+
+        let mut solved = 0;
+        let mut solve = |answer: &str| {
+            solved += 1;
+            Ok(answer.to_string())
+        };
+        assert_eq!(cache.answer("part1", "1 2", || solve("3")).unwrap(), "3");
+        assert_eq!(cache.answer("part1", "1 2", || solve("4")).unwrap(), "3");
+        assert_eq!(cache.answer("part2", "1 2", || solve("4")).unwrap(), "4");
+
+        cache.verify = true;
+        assert_eq!(cache.answer("part2", "1 2", || solve("4")).unwrap(), "4");
+        let err = cache.answer("part1", "1 2", || solve("5"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(err.is_err());
+        assert_eq!(solved, 4);
+    }
+}
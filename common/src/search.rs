@@ -0,0 +1,308 @@
+//! Shortest path searches over implicit graphs, given as a start node and a neighbors function.
+//!
+//! Unlike [`aoc_lib::algo::dijkstra`], the searches stop once the goal is settled and
+//! remember every predecessor on a shortest path, so apart from distances they can
+//! reconstruct a single path or every node on any of the shortest ones.
+//! Pass `|_| false` as the goal to explore the whole graph instead.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Anything edge weights can be summed up in.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// Outcome of a search: the cost of reaching every visited node and how it was reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    dist: HashMap<N, C>,
+    preds: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Hash + Eq + Clone, C: Cost> Paths<N, C> {
+    /// Every goal node reachable at the lowest cost, empty if none were reachable at all.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal()?)
+    }
+
+    /// Cost of the cheapest path to `node`.
+    ///
+    /// When the search stopped early, this is only final for nodes
+    /// no more expensive than the goal.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn dist(&self) -> &HashMap<N, C> {
+        &self.dist
+    }
+
+    /// One of the cheapest paths from the start to `node`, including both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        // The first predecessor is the one which set the node's cost, so it was settled
+        // before the node and this always walks back towards the start, even with free edges,
+        // and the start never gets any, so that's where it stops.
+        while let Some(prev) = self.preds.get(path.last()?).and_then(|preds| preds.first()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node lying on any of the cheapest paths from the start to one of `targets`.
+    pub fn nodes_on_paths<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut res = HashSet::new();
+        let mut stack: Vec<_> = targets
+            .into_iter()
+            .filter(|node| self.dist.contains_key(node))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if res.insert(node.clone()) {
+                stack.extend(self.preds.get(node).into_iter().flatten());
+            }
+        }
+        res
+    }
+}
+
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed, so that [`BinaryHeap`] pops the lowest priority first.
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm, stopping once every cheapest node satisfying `is_goal` is settled.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search, exploring the nodes in order of their cost plus `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost to the goal,
+/// nor drop by more than the cost of an edge, or the results may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = HashMap::from([(start.clone(), C::default())]);
+    let mut preds = HashMap::<N, Vec<N>>::new();
+    let mut goals = Vec::new();
+    let mut best = None;
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start.clone(),
+    }]);
+
+    while let Some(Entry {
+        priority,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+        if dist[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            match dist.get(&next) {
+                Some(&known) if known < next_cost => {}
+                Some(&known) if known == next_cost => {
+                    // free edges can lead back to the start, which no path goes through twice
+                    if next != start {
+                        preds.entry(next).or_default().push(node.clone());
+                    }
+                }
+                _ => {
+                    dist.insert(next.clone(), next_cost);
+                    preds.insert(next.clone(), vec![node.clone()]);
+                    queue.push(Entry {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+
+    Paths { dist, preds, goals }
+}
+
+/// Breadth-first search for graphs where every edge costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::from([(start.clone(), 0)]);
+    let mut preds = HashMap::<N, Vec<N>>::new();
+    let mut goals = Vec::new();
+    let mut best = None;
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let cost = dist[&node];
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if is_goal(&node) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+        for next in neighbors(&node) {
+            match dist.get(&next) {
+                Some(&known) if known == cost + 1 => {
+                    preds.entry(next).or_default().push(node.clone());
+                }
+                Some(_) => {}
+                None => {
+                    dist.insert(next.clone(), cost + 1);
+                    preds.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    Paths { dist, preds, goals }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 3
+    // 0 -1-> 2 -1-> 3
+    // 0 -5-> 3 -1-> 4
+    fn graph(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let paths = dijkstra(0, graph, |&n| n == 3);
+        assert_eq!(paths.goal_cost(), Some(2));
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 3]));
+        assert_eq!(
+            paths.nodes_on_paths(paths.goals()),
+            HashSet::from([0, 1, 2, 3])
+        );
+        assert_eq!(paths.cost(&4), None, "stopped at the goal");
+
+        let all = dijkstra(0, graph, |_| false);
+        assert_eq!(all.goal(), None);
+        assert_eq!(all.cost(&4), Some(3));
+
+        let astar = astar(0, graph, |&n| 3 - n.min(3), |&n| n == 4);
+        assert_eq!(astar.goal_cost(), Some(3));
+    }
+
+    #[test]
+    fn free_cycle() {
+        // 0 -0-> 1 -0-> 0, 1 -1-> 2
+        let graph = |n: &u32| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra(0, graph, |_| false);
+        assert_eq!(paths.path_to(&0), Some(vec![0]));
+        assert_eq!(paths.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(paths.nodes_on_paths([&2]), HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn unweighted() {
+        let neighbors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= 2 && y <= 2)
+        };
+        let paths = bfs((0, 0), neighbors, |&pos| pos == (2, 2));
+        assert_eq!(paths.goal_cost(), Some(4));
+        assert_eq!(paths.path_to(&(2, 2)).unwrap().len(), 5);
+        assert_eq!(paths.nodes_on_paths(paths.goals()).len(), 9);
+        assert!(
+            bfs((0, 0), neighbors, |&pos| pos == (3, 3))
+                .goals()
+                .is_empty()
+        );
+    }
+}
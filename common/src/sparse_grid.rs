@@ -0,0 +1,186 @@
+//! Byte grid without fixed dimensions, for maps that are infinite, tiled, or only known point by point.
+//!
+//! Cells are stored sparsely, so only the ones that were [`set`](SparseGrid::set) take up memory.
+//! A grid can optionally repeat a base [`Grid`] in every direction, in which case cells
+//! that were never set read through to the corresponding cell of the base.
+
+use std::{collections::HashMap, fmt};
+
+use aoc_lib::grid::{Grid, Point};
+
+#[derive(Clone, Default)]
+pub struct SparseGrid<'a> {
+    cells: HashMap<Point, u8>,
+    tile: Option<Grid<'a>>,
+    bounds: Option<(Point, Point)>,
+    bbox: Option<(Point, Point)>,
+}
+
+impl<'a> SparseGrid<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A grid limited to positions between `min` and `max`, inclusive.
+    pub fn bounded(min: Point, max: Point) -> Self {
+        SparseGrid {
+            bounds: Some((min, max)),
+            ..Self::default()
+        }
+    }
+
+    /// An infinite grid made of copies of `base`, with `base`'s (0, 0) at (0, 0).
+    pub fn tiled(base: Grid<'a>) -> Self {
+        SparseGrid {
+            tile: Some(base),
+            ..Self::default()
+        }
+    }
+
+    pub fn is_valid_pos(&self, (x, y): Point) -> bool {
+        self.bounds.is_none_or(|((min_x, min_y), (max_x, max_y))| {
+            (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+        })
+    }
+
+    /// Position in the base grid that `pos` is a copy of, if the grid is tiled.
+    pub fn base_pos(&self, (x, y): Point) -> Option<Point> {
+        self.tile
+            .map(|base| (x.rem_euclid(base.width()), y.rem_euclid(base.height())))
+    }
+
+    /// Which copy of the base grid `pos` falls into, with the original being (0, 0).
+    pub fn tile_of(&self, (x, y): Point) -> Option<Point> {
+        self.tile
+            .map(|base| (x.div_euclid(base.width()), y.div_euclid(base.height())))
+    }
+
+    /// The cell at `pos`: what was set there, or else what the base grid has there if tiled.
+    pub fn get(&self, pos: Point) -> Option<u8> {
+        if !self.is_valid_pos(pos) {
+            return None;
+        }
+        self.cells
+            .get(&pos)
+            .copied()
+            .or_else(|| Some(self.tile?[self.base_pos(pos)?]))
+    }
+
+    /// Whether a cell was explicitly set at `pos`, ignoring the base grid.
+    pub fn contains(&self, pos: Point) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets the cell at `pos`, returning what was previously set there.
+    ///
+    /// # Panics
+    ///
+    /// If `pos` is outside the grid's bounds.
+    pub fn set(&mut self, pos: Point, c: u8) -> Option<u8> {
+        assert!(self.is_valid_pos(pos), "{pos:?} is out of bounds");
+        self.bbox = Some(match self.bbox {
+            None => (pos, pos),
+            Some((min, max)) => (
+                (min.0.min(pos.0), min.1.min(pos.1)),
+                (max.0.max(pos.0), max.1.max(pos.1)),
+            ),
+        });
+        self.cells.insert(pos, c)
+    }
+
+    pub fn remove(&mut self, pos: Point) -> Option<u8> {
+        self.cells.remove(&pos)
+    }
+
+    /// Number of cells that were explicitly set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Explicitly set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, u8)> + '_ {
+        self.cells.iter().map(|(&pos, &c)| (pos, c))
+    }
+
+    /// Smallest rectangle, as inclusive (min, max) corners, containing every cell ever set.
+    /// It doesn't shrink when cells are removed.
+    pub fn bbox(&self) -> Option<(Point, Point)> {
+        self.bbox
+    }
+
+    /// Positions directly above, below, left, and right of `pos` which are within bounds.
+    pub fn orthogonal_pos(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+            .into_iter()
+            .filter(|&p| self.is_valid_pos(p))
+    }
+
+    /// All 8 positions around `pos` which are within bounds.
+    pub fn adjacent_pos(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&p| p != (x, y) && self.is_valid_pos(p))
+    }
+}
+
+/// Draws the bounding box, or the bounds if the grid has them, with `.` for empty cells.
+impl fmt::Display for SparseGrid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds.or(self.bbox) else {
+            return Ok(());
+        };
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                let c = self.get((x, y)).unwrap_or(b'.');
+                write!(f, "{}", c as char)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unbounded() {
+        let mut grid = SparseGrid::new();
+        grid.set((-2, 3), b'#');
+        grid.set((1, -1), b'O');
+        assert_eq!(grid.get((-2, 3)), Some(b'#'));
+        assert_eq!(grid.get((0, 0)), None);
+        assert_eq!(grid.bbox(), Some(((-2, -1), (1, 3))));
+        assert_eq!(grid.orthogonal_pos((100, 100)).count(), 4);
+        assert_eq!(grid.to_string(), "...O\n....\n....\n....\n#...\n");
+    }
+
+    #[test]
+    fn bounded() {
+        let grid = SparseGrid::bounded((0, 0), (2, 2));
+        assert!(grid.is_valid_pos((2, 2)));
+        assert!(!grid.is_valid_pos((3, 0)));
+        assert_eq!(grid.orthogonal_pos((0, 0)).count(), 2);
+        assert_eq!(grid.adjacent_pos((1, 1)).count(), 8);
+        assert_eq!(grid.adjacent_pos((0, 2)).count(), 3);
+    }
+
+    #[test]
+    fn tiled() {
+        let base = Grid::for_str("ab\ncd").unwrap();
+        let mut grid = SparseGrid::tiled(base);
+        assert_eq!(grid.get((-1, -1)), Some(b'd'));
+        assert_eq!(grid.get((4, 3)), Some(b'c'));
+        assert_eq!(grid.base_pos((-3, 5)), Some((1, 1)));
+        assert_eq!(grid.tile_of((-3, 5)), Some((-2, 2)));
+        grid.set((2, 0), b'#');
+        assert_eq!(grid.get((2, 0)), Some(b'#'));
+        assert_eq!(grid.get((0, 0)), Some(b'a'));
+        assert!(!grid.contains((0, 0)));
+    }
+}
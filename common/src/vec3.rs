@@ -0,0 +1,235 @@
+//! Integer 3D vectors and inclusive axis-aligned boxes built out of them.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3::new(0, 0, 0);
+    pub const X: Vec3 = Vec3::new(1, 0, 0);
+    pub const Y: Vec3 = Vec3::new(0, 1, 0);
+    pub const Z: Vec3 = Vec3::new(0, 0, 1);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn map(self, mut f: impl FnMut(i64) -> i64) -> Self {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    /// Combines the two vectors component by component.
+    pub fn zip_with(self, other: Self, mut f: impl FnMut(i64, i64) -> i64) -> Self {
+        Vec3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, i64::min)
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, i64::max)
+    }
+
+    pub fn dot(self, other: Self) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+}
+
+impl From<(i64, i64, i64)> for Vec3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl From<Vec3> for (i64, i64, i64) {
+    fn from(v: Vec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, rhs: Vec3) -> Vec3 {
+        self.zip_with(rhs, i64::add)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Vec3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, rhs: Vec3) -> Vec3 {
+        self.zip_with(rhs, i64::sub)
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, rhs: Vec3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Vec3 {
+        self.map(i64::neg)
+    }
+}
+
+impl Mul<i64> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: i64) -> Vec3 {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVec3Error(String);
+
+impl fmt::Display for ParseVec3Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid vector: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseVec3Error {}
+
+/// Parses `x,y,z`, allowing whitespace around the components, so `x, y, z` works too.
+impl FromStr for Vec3 {
+    type Err = ParseVec3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseVec3Error(s.to_string());
+        let mut i = s.split(',').map(|c| c.trim().parse().map_err(|_| err()));
+        let v = Vec3::new(
+            i.next().ok_or_else(err)??,
+            i.next().ok_or_else(err)??,
+            i.next().ok_or_else(err)??,
+        );
+        match i.next() {
+            Some(_) => Err(err()),
+            None => Ok(v),
+        }
+    }
+}
+
+/// Axis-aligned box covering every point from `min` to `max`, both inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    /// The box spanning two opposite corners, given in any order.
+    pub fn new(a: Vec3, b: Vec3) -> Self {
+        Aabb3 {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Length of each side, counting both ends.
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min + Vec3::new(1, 1, 1)
+    }
+
+    /// Number of integer points inside the box.
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, p: Vec3) -> bool {
+        self.min.min(p) == self.min && self.max.max(p) == self.max
+    }
+
+    pub fn intersects(&self, other: &Aabb3) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Aabb3) -> Option<Aabb3> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Aabb3 { min, max })
+    }
+
+    pub fn translate(&self, by: Vec3) -> Aabb3 {
+        Aabb3 {
+            min: self.min + by,
+            max: self.max + by,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_ops() {
+        let a = Vec3::new(1, 2, 3);
+        let b: Vec3 = "4, -5, 6".parse().unwrap();
+        assert_eq!(a + b, Vec3::new(5, -3, 9));
+        assert_eq!(a - b, Vec3::new(-3, 7, -3));
+        assert_eq!(-a * 2, Vec3::new(-2, -4, -6));
+        assert_eq!(a.dot(b), 12);
+        assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+        assert_eq!(b.manhattan(), 15);
+        assert!("1,2".parse::<Vec3>().is_err());
+        assert!("1,2,3,4".parse::<Vec3>().is_err());
+    }
+
+    #[test]
+    fn boxes() {
+        let a = Aabb3::new(Vec3::new(2, 0, 5), Vec3::new(0, 2, 5));
+        assert_eq!(a.min, Vec3::new(0, 0, 5));
+        assert_eq!(a.volume(), 9);
+        let b = a.translate(Vec3::new(2, 2, 0));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Aabb3::new(Vec3::new(2, 2, 5), Vec3::new(2, 2, 5)))
+        );
+        assert!(!a.intersects(&b.translate(Vec3::Z)));
+        assert!(a.contains(Vec3::new(1, 1, 5)));
+        assert!(!a.contains(Vec3::new(1, 1, 4)));
+    }
+}
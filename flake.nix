@@ -25,6 +25,7 @@
             aoc-utils.packages.${pkgs.system}.aoc-cli
             pkgs.pkg-config
             pkgs.z3
+            pkgs.cargo-fuzz
           ]
           ++ pkgs.lib.optionals pkgs.stdenv.isDarwin [pkgs.libiconv];
       };